@@ -3,10 +3,23 @@ use leptos_meta::*;
 use leptos_router::*;
 
 // # Modules
-use perse_data::views::schema::View as PerseView;
+use perse_data::views::schema::RenderedView;
+use perse_utils::results::{ErrorTypes, PerseError};
 use perse_view::{
-    components::{initial_state::InitialState, loader::Loader, PerseComponent},
-    views::{errors::NotFound, home::Home, new::New},
+    components::{content_body::ContentBody, head_content::HeadContent, initial_state::InitialState, loader::Loader, PerseComponent},
+    views::{
+        api_tokens::ApiTokens,
+        edit::Edit,
+        errors::{InternalError, NotFound},
+        home::Home,
+        login::{Login, Logout},
+        new::New,
+        redirects::Redirects,
+        revisions::Revisions,
+        settings::Settings,
+        trash::Trash,
+        users::Users,
+    },
 };
 
 /// # Perse Controller
@@ -35,18 +48,62 @@ fn SetupRouter() -> impl IntoView {
                 <Route
                     path=""
                     view=Home
+                    ssr=SsrMode::Async
                 />
 
                 // Setup the System routes
+                <Route
+                    path="/p/login"
+                    view=Login
+                />
+                <Route
+                    path="/p/logout"
+                    view=Logout
+                />
                 <Route
                     path="/p/new"
                     view=New
                     ssr=SsrMode::Async
                 />
+                <Route
+                    path="/p/edit/:id"
+                    view=Edit
+                    ssr=SsrMode::Async
+                />
+                <Route
+                    path="/p/revisions/:id"
+                    view=Revisions
+                    ssr=SsrMode::Async
+                />
+                <Route
+                    path="/p/trash"
+                    view=Trash
+                    ssr=SsrMode::Async
+                />
+                <Route
+                    path="/p/redirects"
+                    view=Redirects
+                    ssr=SsrMode::Async
+                />
+                <Route
+                    path="/p/settings"
+                    view=Settings
+                    ssr=SsrMode::Async
+                />
+                <Route
+                    path="/p/users"
+                    view=Users
+                    ssr=SsrMode::Async
+                />
+                <Route
+                    path="/p/tokens"
+                    view=ApiTokens
+                    ssr=SsrMode::Async
+                />
 
-                // Look for other routes in the Database
+                // Look for other routes in the Database, including nested routes
                 <Route
-                    path="/:route"
+                    path="/*route"
                     view=PathFinder
                     ssr=SsrMode::Async
                 />
@@ -75,6 +132,8 @@ fn PathFinder() -> impl IntoView {
                 .unwrap()
         })
     };
+    let preview_query = use_query_map();
+    let preview = move || preview_query.with(|q| q.get("preview").cloned());
 
     // ## Server Functions
 
@@ -93,7 +152,7 @@ fn PathFinder() -> impl IntoView {
         // Signal source
         get_route_signal,
         // Loader
-        move |_signal_count| async move { get_route(requested_route()).await },
+        move |_signal_count| async move { get_route(requested_route(), preview()).await },
     );
 
     // Action for the Get Route signal
@@ -105,17 +164,24 @@ fn PathFinder() -> impl IntoView {
     view! {
         <Transition fallback=|| Loader::build(None).into_view()>
             // Action for the Get Route signal
-            {move || get_route_signal_action().map(|response| response.map(|view| view! {
-                // Set Metadata
-                <Title text=view.title />
-                <Meta name="description" content=view.description.unwrap_or_default() />
-
-                {view.content_body}
-            }.into_view())
+            {move || get_route_signal_action().map(|response| response.map(|rendered| match rendered {
+                Some(RenderedView { view, head, body }) => view! {
+                    // Set Metadata
+                    <Title text=view.title />
+                    <Meta name="description" content=view.description.unwrap_or_default() />
+                    {preview().map(|_| view! { <Meta name="robots" content="noindex" /> })}
+                    {HeadContent::build_iter(Some(head))}
+
+                    {ContentBody::build(body)}
+                }.into_view(),
+                // The requested route has been redirected
+                None => ().into_view(),
+            })
             // View for the server error, or if the requested route does not exist
-            .unwrap_or_else(|_err_or_not_found| view! {
-                <NotFound err=None />
-            }.into_view()))
+            .unwrap_or_else(|err| match PerseError::from(err).error_type() {
+                ErrorTypes::NotFound => view! { <NotFound err=None /> }.into_view(),
+                _ => view! { <InternalError /> }.into_view(),
+            }))
             // Initial state
             .unwrap_or_else(|| Some(InitialState::build(None)).collect_view())}
         </Transition>
@@ -124,17 +190,45 @@ fn PathFinder() -> impl IntoView {
 
 /// # Retrieve the requested route from the database
 ///
+/// ## Fields
+/// * `route` - The requested route, which may be nested such as `docs/getting-started`
+/// * `preview` - A signed Preview token, to render a View's unpublished changes instead
+///
 /// ## Returns  
-/// * `Result<View, ServerFnError>` - The requested route, if one exists
+/// * `Result<Option<RenderedView>, ServerFnError>` - The requested route prepared for rendering, or `None` if it has been redirected
 #[server(name = GetRouteHandler, prefix = "/api/v1", endpoint = "views/lookup")]
-async fn get_route(route: String) -> Result<PerseView, ServerFnError> {
-    use perse_data::Database;
+async fn get_route(route: String, preview: Option<String>) -> Result<Option<RenderedView>, ServerFnError> {
+    use actix_web::http::StatusCode;
+    use leptos_actix::ResponseOptions;
+    use perse_data::{redirects::schema::Redirect, views::schema::View as PerseView, Database};
 
     // Server Validation
     if route.is_empty() {
         Err(ServerFnError::new("Route is empty".to_string()))?;
     }
 
-    // Get the View using the requested route
-    Ok(PerseView::get_by_route(Database::get()?, &route).await?)
+    // Get the previewed View
+    if let Some(token) = preview {
+        return Ok(Some(PerseView::get_preview(Database::get()?, &token, Some(&route)).await?.render()));
+    }
+
+    // Get the View using the requested route, or follow a Redirect if there isn't one
+    if let Some(view) = PerseView::get_by_route(Database::get()?, &route).await? {
+        return Ok(Some(view.render()));
+    }
+
+    let Some(redirect) = Redirect::find(Database::get()?, &route).await? else {
+        Err(PerseError::new(ErrorTypes::NotFound, "The page you are looking for does not exist."))?
+    };
+
+    leptos_actix::redirect(&redirect.location);
+
+    // Page requests are redirected with a 302 by default, so mark permanent Redirects as such
+    if let Some(response) = use_context::<ResponseOptions>() {
+        if redirect.status_code == 301 && response.0.read().status == Some(StatusCode::FOUND) {
+            response.set_status(StatusCode::MOVED_PERMANENTLY);
+        }
+    }
+
+    Ok(None)
 }
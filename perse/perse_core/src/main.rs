@@ -1,15 +1,25 @@
 /// # Perse
 
+// # Modules
+#[cfg(feature = "ssr")]
+mod services;
+
 /// # Backend Entry Point
 #[cfg(feature = "ssr")]
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     use actix_files::Files;
-    use actix_web::*;
+    use actix_session::{config::CookieContentSecurity, storage::CookieSessionStore, SessionMiddleware};
+    use actix_web::{cookie::Key, *};
     use leptos::{logging::log, *};
     use leptos_actix::{generate_route_list, LeptosRoutes};
     use leptos_config::{ConfFile, LeptosOptions};
-    use perse_data::Database;
+    use perse_data::{
+        config::{Configuration, EnvironmentTypes},
+        users::schema::User,
+        views::schema::View as PerseView,
+        Database,
+    };
     use std::net::SocketAddr;
     console_error_panic_hook::set_once();
 
@@ -24,10 +34,26 @@ async fn main() -> std::io::Result<()> {
     log!("Initialising the Database connection pool and checking for pending migrations...");
     let database = Database::setup().await;
 
+    // Creating the first User
+    log!("Checking for Users...");
+    User::seed(database)
+        .await
+        .expect("Failed to create the first User.");
+
+    // Starting the View scheduler
+    log!("Starting the View scheduler...");
+    actix_web::rt::spawn(PerseView::run_schedules(
+        database,
+        Configuration::setup().scheduler_interval,
+    ));
+
     // Importing the Routes and Components
     log!("Importing the Routes and Components...");
     use perse_controller::*;
 
+    // Derive the key used to sign session cookies
+    let session_key: Key = Key::derive_from(Configuration::setup().session_secret.as_bytes());
+
     // Start Web Server
     log!("Launching Perse!");
     HttpServer::new(move || {
@@ -40,18 +66,43 @@ async fn main() -> std::io::Result<()> {
             .service(Files::new("/assets", site_root))
             // serve the Favicon from /favicon.ico
             .service(favicon)
+            // serve the Robots rules from /robots.txt
+            .service(services::robots::robots)
+            // serve the Sitemap, split into pages when there are too many Views
+            .service(services::sitemap::sitemap)
+            .service(services::sitemap::sitemap_page)
+            // serve the RSS and Atom feeds of the newest Views
+            .service(services::feeds::rss)
+            .service(services::feeds::atom)
+            // serve the REST API, and its OpenAPI specification
+            .service(services::rest::scope())
+            .service(services::openapi::specification)
             // setup the Routes
             .leptos_routes(
                 leptos_options.to_owned(),
                 generate_route_list(|| view! { <Controller/> }),
                 || view! { <Controller/> },
             )
+            // accept request bodies as large as the configured content size limits allow
+            .app_data(web::PayloadConfig::new(Configuration::setup().payload_limit()))
+            .app_data(web::FormConfig::default().limit(Configuration::setup().payload_limit()))
             // set the Application State
             .app_data(web::Data::new(leptos_options.to_owned()))
             .app_data(web::Data::new(PerseState {
                 _database: database,
-                // env: Configuration::setup(),
+                _configuration: Configuration::setup(),
             }))
+            // require a signed in User for the admin area
+            .wrap_fn(services::auth::guard)
+            // reject request bodies larger than the configured content size limits allow, including those of server functions
+            .wrap(services::payload::PayloadLimit(Configuration::setup().payload_limit()))
+            // keep the signed in User in a signed session cookie
+            .wrap(
+                SessionMiddleware::builder(CookieSessionStore::default(), session_key.clone())
+                    .cookie_content_security(CookieContentSecurity::Signed)
+                    .cookie_secure(Configuration::setup().environment == EnvironmentTypes::Production)
+                    .build(),
+            )
         //.wrap(middleware::Compress::default())
     })
     .bind(&addr)?
@@ -84,7 +135,7 @@ pub fn main() {}
 #[cfg(feature = "ssr")]
 pub struct PerseState<'a> {
     _database: &'a perse_data::DatabasePool,
-    // env: Configuration,
+    _configuration: &'a perse_data::config::Configuration,
 }
 
 /// # Favicon Configuration
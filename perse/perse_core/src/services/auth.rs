@@ -0,0 +1,89 @@
+use actix_session::SessionExt;
+use actix_web::{
+    body::EitherBody,
+    dev::{Service, ServiceRequest, ServiceResponse},
+    http::header,
+    Error, HttpResponse,
+};
+use futures_util::future::{ready, FutureExt, LocalBoxFuture};
+use perse_data::users::schema::SESSION_USER_ID;
+
+/// # Admin Area Guard
+///
+/// Sends requests for pages in the admin area to the sign in page, unless a User is signed in.
+/// The server functions behind these pages check the User themselves.
+///
+/// ## Fields
+/// * `req` - The incoming request
+/// * `service` - The service handling the request
+///
+/// ## Returns
+/// * `LocalBoxFuture<'static, Result<ServiceResponse<EitherBody<B>>, Error>>` - The response
+pub fn guard<S, B>(req: ServiceRequest, service: &S) -> LocalBoxFuture<'static, Result<ServiceResponse<EitherBody<B>>, Error>>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    S::Future: 'static,
+    B: 'static,
+{
+    let path: &str = req.path();
+    let is_protected: bool = (path == "/p" || path.starts_with("/p/")) && path != "/p/login";
+    let is_signed_in: bool = matches!(req.get_session().get::<String>(SESSION_USER_ID), Ok(Some(_)));
+
+    if is_protected && !is_signed_in {
+        // Return to the full requested URL once signed in
+        let next: String = form_urlencoded::byte_serialize(
+            req.uri().path_and_query().map_or(path, |path_and_query| path_and_query.as_str()).as_bytes(),
+        )
+        .collect();
+        let location: String = format!("/p/login?next={next}");
+        let response = HttpResponse::SeeOther()
+            .insert_header((header::LOCATION, location))
+            .finish()
+            .map_into_right_body();
+
+        return ready(Ok(req.into_response(response))).boxed_local();
+    }
+
+    service
+        .call(req)
+        .map(|response| response.map(ServiceResponse::map_into_left_body))
+        .boxed_local()
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_session::{storage::CookieSessionStore, SessionMiddleware};
+    use actix_web::{cookie::Key, http::header, test, web, App, HttpResponse};
+
+    // ## Request a path through the guard, without being signed in, returning where it redirects to
+    async fn redirect(uri: &str) -> Option<String> {
+        let app = test::init_service(
+            App::new()
+                .default_service(web::to(HttpResponse::Ok))
+                .wrap_fn(super::guard)
+                .wrap(SessionMiddleware::new(CookieSessionStore::default(), Key::generate())),
+        )
+        .await;
+        let response = test::call_service(&app, test::TestRequest::get().uri(uri).to_request()).await;
+
+        response
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .map(str::to_string)
+    }
+
+    #[actix_web::test]
+    async fn admin_area_redirects_to_login_with_the_full_url() {
+        assert_eq!(
+            redirect("/p/views/edit?id=a&b=c").await.as_deref(),
+            Some("/p/login?next=%2Fp%2Fviews%2Fedit%3Fid%3Da%26b%3Dc")
+        );
+    }
+
+    #[actix_web::test]
+    async fn public_pages_and_login_are_not_guarded() {
+        assert_eq!(redirect("/about").await, None);
+        assert_eq!(redirect("/p/login").await, None);
+    }
+}
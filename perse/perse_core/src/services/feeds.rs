@@ -0,0 +1,440 @@
+use actix_web::{
+    error::{ErrorInternalServerError, ErrorNotFound},
+    get,
+    http::header::{self, EntityTag, HttpDate},
+    web::Query,
+    HttpRequest, HttpResponse,
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use perse_data::{
+    content::schema::RenderedBody,
+    views::schema::View as PerseView,
+    Database, DatabasePool,
+};
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    time::SystemTime,
+};
+
+// # Modules
+use super::{base_url, escape_xml};
+
+/// # Maximum number of Views in a feed
+const FEED_LIMIT: i64 = 50;
+
+/// # RSS Feed
+///
+/// Lists the newest public Views as an RSS 2.0 feed, optionally filtered with the `parent` and `tag` query parameters.
+#[get("feed.xml")]
+pub async fn rss(req: HttpRequest) -> actix_web::Result<HttpResponse> {
+    let conn = Database::get().map_err(|err| ErrorInternalServerError(format!("{err:?}")))?;
+    let feed: Feed = Feed::build(&req, conn).await?;
+
+    if let Some(response) = feed.not_modified(&req) {
+        return Ok(response);
+    }
+
+    let mut body = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\">\n<channel>\n  <title>{}</title>\n  <link>{}</link>\n  <description>{}</description>\n  <atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\" />\n",
+        escape_xml(&feed.title),
+        escape_xml(&feed.link),
+        escape_xml(&feed.description),
+        escape_xml(&feed.self_link(&req)),
+    );
+    if let Some(updated_at) = feed.updated_at {
+        body += &format!("  <lastBuildDate>{}</lastBuildDate>\n", HttpDate::from(SystemTime::from(updated_at)));
+    }
+
+    for entry in &feed.entries {
+        body += &format!(
+            "  <item>\n    <title>{}</title>\n    <link>{}</link>\n    <guid isPermaLink=\"false\">urn:uuid:{}</guid>\n    <pubDate>{}</pubDate>\n",
+            escape_xml(&entry.title),
+            escape_xml(&entry.link),
+            entry.id,
+            HttpDate::from(SystemTime::from(entry.created_at)),
+        );
+        if let Some(summary) = &entry.summary {
+            body += &format!("    <description>{}</description>\n", escape_xml(summary));
+        }
+        for tag in &entry.tags {
+            body += &format!("    <category>{}</category>\n", escape_xml(tag));
+        }
+        if let Some(content) = &entry.content {
+            body += &format!("    <content:encoded>{}</content:encoded>\n", escape_xml(content));
+        }
+        body += "  </item>\n";
+    }
+    body += "</channel>\n</rss>\n";
+
+    Ok(feed.respond("application/rss+xml; charset=utf-8", body))
+}
+
+/// # Atom Feed
+///
+/// Lists the newest public Views as an Atom feed, optionally filtered with the `parent` and `tag` query parameters.
+#[get("atom.xml")]
+pub async fn atom(req: HttpRequest) -> actix_web::Result<HttpResponse> {
+    let conn = Database::get().map_err(|err| ErrorInternalServerError(format!("{err:?}")))?;
+    let feed: Feed = Feed::build(&req, conn).await?;
+
+    if let Some(response) = feed.not_modified(&req) {
+        return Ok(response);
+    }
+
+    let mut body = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\">\n  <id>{}</id>\n  <title>{}</title>\n  <subtitle>{}</subtitle>\n  <link href=\"{}\" />\n  <link href=\"{}\" rel=\"self\" type=\"application/atom+xml\" />\n  <updated>{}</updated>\n",
+        escape_xml(&feed.self_link(&req)),
+        escape_xml(&feed.title),
+        escape_xml(&feed.description),
+        escape_xml(&feed.link),
+        escape_xml(&feed.self_link(&req)),
+        rfc3339(feed.updated_at.unwrap_or_default()),
+    );
+
+    for entry in &feed.entries {
+        body += &format!(
+            "  <entry>\n    <id>urn:uuid:{}</id>\n    <title>{}</title>\n    <link href=\"{}\" />\n    <published>{}</published>\n    <updated>{}</updated>\n",
+            entry.id,
+            escape_xml(&entry.title),
+            escape_xml(&entry.link),
+            rfc3339(entry.created_at),
+            rfc3339(entry.updated_at),
+        );
+        if let Some(summary) = &entry.summary {
+            body += &format!("    <summary>{}</summary>\n", escape_xml(summary));
+        }
+        for tag in &entry.tags {
+            body += &format!("    <category term=\"{}\" />\n", escape_xml(tag));
+        }
+        if let Some(content) = &entry.content {
+            body += &format!("    <content type=\"html\">{}</content>\n", escape_xml(content));
+        }
+        body += "  </entry>\n";
+    }
+    body += "</feed>\n";
+
+    Ok(feed.respond("application/atom+xml; charset=utf-8", body))
+}
+
+// ## A feed of Views, shared by the RSS and Atom formats
+//
+// ### Fields
+// * `title` - Title of the feed, from the parent View or the Homepage
+// * `description` - Description of the feed
+// * `link` - URL of the page the feed belongs to
+// * `query` - The filters of the feed, as a query string
+// * `entries` - The Views in the feed, newest first
+// * `updated_at` - The latest change to a View in the feed
+// * `etag` - Entity tag identifying this version of the feed
+struct Feed {
+    title: String,
+    description: String,
+    link: String,
+    query: String,
+    entries: Vec<FeedEntry>,
+    updated_at: Option<DateTime<Utc>>,
+    etag: EntityTag,
+}
+
+// ## A View in a feed
+//
+// ### Fields
+// * `id` - ID of the View
+// * `title` - Title of the View
+// * `link` - URL of the View
+// * `summary` - Description of the View
+// * `content` - Rendered body of the View, as HTML
+// * `tags` - Tags of the View
+// * `created_at` - Creation date of the View
+// * `updated_at` - Date the View was last updated
+struct FeedEntry {
+    id: String,
+    title: String,
+    link: String,
+    summary: Option<String>,
+    content: Option<String>,
+    tags: Vec<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl Feed {
+    // ## Build the feed for a request
+    //
+    // ### Fields
+    // * `req` - The incoming request, with the optional `parent` and `tag` query parameters
+    // * `conn` - The database connection to use
+    //
+    // ### Returns
+    // * `actix_web::Result<Self>` - The feed, or not found when the parent View doesn't exist
+    async fn build(req: &HttpRequest, conn: &DatabasePool) -> actix_web::Result<Self> {
+        let base_url: String = base_url(req);
+
+        let filters = Query::<HashMap<String, String>>::from_query(req.query_string()).unwrap_or_else(|_| Query(HashMap::new()));
+        let parent: Option<&str> = filters
+            .get("parent")
+            .map(|parent| parent.trim_matches('/'))
+            .filter(|parent| !parent.is_empty());
+        let tag: Option<String> = filters
+            .get("tag")
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty());
+
+        // Name the feed after the parent View, or the Homepage
+        let parent: Option<PerseView> = match parent {
+            Some(parent) => Some(
+                PerseView::get_by_route(conn, parent)
+                    .await
+                    .map_err(|err| ErrorInternalServerError(format!("{err:?}")))?
+                    .ok_or_else(|| ErrorNotFound("The parent of the feed does not exist."))?,
+            ),
+            None => None,
+        };
+        let (title, description, link) = match &parent {
+            Some(parent) => (parent.title.clone(), parent.description.clone(), format!("{base_url}/{}", parent.path)),
+            None => match PerseView::get_homepage(conn)
+                .await
+                .map_err(|err| ErrorInternalServerError(format!("{err:?}")))?
+            {
+                Some(homepage) => (homepage.title, homepage.description, format!("{base_url}/")),
+                None => ("Perse".to_string(), None, format!("{base_url}/")),
+            },
+        };
+
+        let views: Vec<PerseView> = PerseView::get_feed(
+            conn,
+            parent.as_ref().and_then(|parent| parent.id.as_ref()),
+            tag.as_deref(),
+            FEED_LIMIT,
+        )
+        .await
+        .map_err(|err| ErrorInternalServerError(format!("{err:?}")))?;
+
+        let entries: Vec<FeedEntry> = views
+            .into_iter()
+            .map(|view| FeedEntry::build(&base_url, view))
+            .collect();
+        let updated_at: Option<DateTime<Utc>> = entries.iter().map(|entry| entry.updated_at).max();
+
+        // Identify this version of the feed by its filters and the version of each View
+        let mut hasher = DefaultHasher::new();
+        (req.path(), &parent.as_ref().map(|parent| &parent.path), &tag).hash(&mut hasher);
+        for entry in &entries {
+            (&entry.id, entry.updated_at).hash(&mut hasher);
+        }
+        let etag = EntityTag::new_strong(format!("{:016x}", hasher.finish()));
+
+        Ok(Self {
+            title,
+            description: description.unwrap_or_default(),
+            link,
+            query: req.query_string().to_string(),
+            entries,
+            updated_at,
+            etag,
+        })
+    }
+
+    // ## Build the URL of the feed itself
+    //
+    // ### Fields
+    // * `req` - The incoming request
+    //
+    // ### Returns
+    // * `String` - The URL of the feed, including its filters
+    fn self_link(&self, req: &HttpRequest) -> String {
+        match self.query.is_empty() {
+            true => format!("{}{}", base_url(req), req.path()),
+            false => format!("{}{}?{}", base_url(req), req.path(), self.query),
+        }
+    }
+
+    // ## Answer a conditional request when the client already has this version of the feed
+    //
+    // ### Fields
+    // * `req` - The incoming request
+    //
+    // ### Returns
+    // * `Option<HttpResponse>` - A not modified response, if the feed hasn't changed
+    fn not_modified(&self, req: &HttpRequest) -> Option<HttpResponse> {
+        let headers = req.headers();
+
+        // The entity tag takes precedence over the modification date
+        let not_modified: bool = match headers.get(header::IF_NONE_MATCH).and_then(|value| value.to_str().ok()) {
+            Some(if_none_match) => if_none_match.split(',').any(|etag| {
+                let etag: &str = etag.trim();
+                etag == "*" || etag.parse::<EntityTag>().is_ok_and(|etag| etag.weak_eq(&self.etag))
+            }),
+            None => headers
+                .get(header::IF_MODIFIED_SINCE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<HttpDate>().ok())
+                .zip(self.updated_at)
+                // Dates in headers are only precise to the second
+                .is_some_and(|(since, updated_at)| updated_at.timestamp() <= DateTime::<Utc>::from(SystemTime::from(since)).timestamp()),
+        };
+
+        not_modified.then(|| self.headers(&mut HttpResponse::NotModified()).finish())
+    }
+
+    // ## Respond with the feed
+    //
+    // ### Fields
+    // * `content_type` - The content type of the feed format
+    // * `body` - The feed document
+    //
+    // ### Returns
+    // * `HttpResponse` - The feed, with its caching headers
+    fn respond(&self, content_type: &str, body: String) -> HttpResponse {
+        self.headers(&mut HttpResponse::Ok())
+            .content_type(content_type)
+            .body(body)
+    }
+
+    // ## Add the caching headers of the feed to a response
+    //
+    // ### Fields
+    // * `response` - The response to add the headers to
+    //
+    // ### Returns
+    // * `&mut HttpResponseBuilder` - The response
+    fn headers<'r>(&self, response: &'r mut actix_web::HttpResponseBuilder) -> &'r mut actix_web::HttpResponseBuilder {
+        response.insert_header(header::ETag(self.etag.clone()));
+        if let Some(updated_at) = self.updated_at {
+            response.insert_header(header::LastModified(SystemTime::from(updated_at).into()));
+        }
+
+        response
+    }
+}
+
+impl FeedEntry {
+    // ## Build the feed entry for a View
+    //
+    // ### Fields
+    // * `base_url` - The canonical base URL of the site
+    // * `view` - The View to list
+    //
+    // ### Returns
+    // * `Self` - The feed entry, with the Homepage at the root URL
+    fn build(base_url: &str, view: PerseView) -> Self {
+        let path: &str = match view.is_homepage {
+            true => "",
+            false => &view.path,
+        };
+        let link: String = format!("{base_url}/{path}");
+
+        let rendered = view.render();
+        let view: PerseView = rendered.view;
+        let created_at: DateTime<Utc> = view.created_at.as_ref().map(NaiveDateTime::and_utc).unwrap_or_default();
+
+        Self {
+            id: view.id.map(|id| id.to_string()).unwrap_or_default(),
+            title: view.title,
+            link,
+            summary: view.description,
+            content: rendered.body.map(|body| match body {
+                RenderedBody::Text(text) => escape_xml(&text),
+                RenderedBody::Html(html) => html,
+            }),
+            tags: view.tags,
+            created_at,
+            updated_at: view.updated_at.as_ref().map(NaiveDateTime::and_utc).unwrap_or(created_at),
+        }
+    }
+}
+
+// ## Format a date as required by Atom
+//
+// ### Fields
+// * `date` - The date to format
+//
+// ### Returns
+// * `String` - The date as RFC 3339, in UTC
+fn rfc3339(date: DateTime<Utc>) -> String {
+    date.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test};
+
+    // ## Create a public View
+    //
+    // ### Fields
+    // * `route` - Route of the View
+    // * `tags` - Tags of the View
+    async fn create(pool: &DatabasePool, route: &str, tags: &[&str]) {
+        sqlx::query(
+            "
+            INSERT INTO views (visibility, title, route, tags, published_at)
+            VALUES ('VisibilityPublic', $1, $1, $2, CURRENT_TIMESTAMP)
+            ",
+        )
+        .bind(route)
+        .bind(tags)
+        .execute(pool)
+        .await
+        .expect("Failed to create the View.");
+    }
+
+    // ## Build the feed for a request
+    //
+    // ### Fields
+    // * `pool` - The database connection to use
+    // * `req` - The request for the feed
+    async fn feed(pool: &DatabasePool, req: test::TestRequest) -> (Feed, HttpRequest) {
+        super::super::configure();
+        let req: HttpRequest = req.to_http_request();
+
+        (Feed::build(&req, pool).await.expect("Failed to build the feed."), req)
+    }
+
+    // ## List the titles of the Views in a feed
+    fn titles(feed: &Feed) -> Vec<&str> {
+        feed.entries.iter().map(|entry| entry.title.as_str()).collect()
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn feed_is_filtered_by_tag(pool: DatabasePool) {
+        create(&pool, "release", &["rust", "news"]).await;
+        create(&pool, "recipe", &["cooking"]).await;
+        create(&pool, "about", &[]).await;
+
+        let (tagged, _) = feed(&pool, test::TestRequest::get().uri("/feed.xml?tag=%20Rust%20")).await;
+        assert_eq!(titles(&tagged), ["release"]);
+
+        let (all, _) = feed(&pool, test::TestRequest::get().uri("/feed.xml")).await;
+        assert_eq!(all.entries.len(), 3);
+        assert_ne!(tagged.etag, all.etag);
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn unchanged_feed_is_not_modified(pool: DatabasePool) {
+        create(&pool, "release", &["news"]).await;
+
+        let (current, _) = feed(&pool, test::TestRequest::get().uri("/feed.xml")).await;
+        let etag: String = current.etag.to_string();
+        let updated_at: HttpDate = SystemTime::from(current.updated_at.expect("The feed has no entries.")).into();
+
+        let (unchanged, req) = feed(&pool, test::TestRequest::get().uri("/feed.xml").insert_header((header::IF_NONE_MATCH, etag.as_str()))).await;
+        let response: HttpResponse = unchanged.not_modified(&req).expect("The feed should not be modified.");
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers().get(header::ETAG).and_then(|value| value.to_str().ok()), Some(etag.as_str()));
+
+        let (unchanged, req) = feed(&pool, test::TestRequest::get().uri("/feed.xml").insert_header(header::IfModifiedSince(updated_at))).await;
+        assert!(unchanged.not_modified(&req).is_some());
+
+        // Changing a View in the feed changes its entity tag
+        sqlx::query("UPDATE views SET title = 'Release notes', updated_at = CURRENT_TIMESTAMP + INTERVAL '1 second'")
+            .execute(&pool)
+            .await
+            .expect("Failed to update the View.");
+
+        let (changed, req) = feed(&pool, test::TestRequest::get().uri("/feed.xml").insert_header((header::IF_NONE_MATCH, etag.as_str()))).await;
+        assert!(changed.not_modified(&req).is_none());
+        assert_ne!(changed.etag.to_string(), etag);
+    }
+}
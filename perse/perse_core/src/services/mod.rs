@@ -0,0 +1,59 @@
+// # Modules
+pub mod auth;
+pub mod feeds;
+pub mod openapi;
+pub mod payload;
+pub mod rest;
+pub mod robots;
+pub mod sitemap;
+
+/// # Determine the canonical base URL of the site
+///
+/// ## Fields
+/// * `req` - The incoming request, used when no base URL has been configured
+///
+/// ## Returns
+/// * `String` - The base URL, without a trailing slash
+fn base_url(req: &actix_web::HttpRequest) -> String {
+    use perse_data::config::Configuration;
+
+    Configuration::get().base_url.clone().unwrap_or_else(|| {
+        let connection = req.connection_info();
+        format!("{}://{}", connection.scheme(), connection.host())
+    })
+}
+
+/// # Escape text for use in an XML document
+///
+/// ## Fields
+/// * `text` - The text to escape
+///
+/// ## Returns
+/// * `String` - The escaped text
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// # Configure Perse for the tests, before the configuration is first used
+///
+/// Content size limits are kept small, so the request body limit can be tested.
+///
+/// ## Returns
+/// * `&'static Configuration` - The configuration
+#[cfg(test)]
+fn configure() -> &'static perse_data::config::Configuration {
+    static CONFIGURE: std::sync::Once = std::sync::Once::new();
+
+    CONFIGURE.call_once(|| {
+        std::env::set_var("PERSE_PREVIEW_SECRET", "test-preview-secret");
+        std::env::set_var("PERSE_SESSION_SECRET", "test-session-secret-at-least-32-bytes");
+        std::env::set_var("PERSE_CONTENT_BODY_MAX_SIZE", "1024");
+        std::env::set_var("PERSE_CONTENT_HEAD_MAX_SIZE", "1024");
+    });
+
+    perse_data::config::Configuration::get()
+}
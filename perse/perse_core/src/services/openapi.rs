@@ -0,0 +1,94 @@
+use actix_web::{get, HttpResponse};
+use perse_data::views::schema::{NewView, View, ViewContentFormatTypes, ViewPatch, ViewUpdate, ViewVisibilityTypes};
+use perse_utils::results::{ErrorTypes, PerseError};
+use utoipa::{
+    openapi::{
+        security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+        OpenApi as OpenApiDocument,
+    },
+    Modify, OpenApi,
+};
+
+// # Modules
+use super::rest;
+
+/// # OpenAPI Document
+///
+/// Describes the REST API, generated from the API's types so it can't drift from them.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Perse",
+        version = "1",
+        description = "The REST API of a Perse site.",
+    ),
+    paths(
+        rest::get_views,
+        rest::new_view,
+        rest::get_view,
+        rest::update_view,
+        rest::delete_view,
+    ),
+    components(schemas(View, NewView, ViewUpdate, ViewPatch, ViewVisibilityTypes, ViewContentFormatTypes, PerseError, ErrorTypes)),
+    modifiers(&Authentication),
+    security(("api_token" = []), ("session" = [])),
+    tags((name = "Views", description = "The pages of the site")),
+)]
+pub struct ApiDoc;
+
+/// # OpenAPI Specification
+///
+/// Serves the OpenAPI document of the REST API.
+#[get("/api/v1/openapi.json")]
+pub async fn specification() -> HttpResponse {
+    HttpResponse::Ok().json(ApiDoc::openapi())
+}
+
+// ## Describe how requests to the API are authenticated
+struct Authentication;
+
+impl Modify for Authentication {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+
+        // API Tokens, issued by a User from the admin area
+        components.add_security_scheme(
+            "api_token",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+
+        // The session of a User signed in to the admin area
+        components.add_security_scheme("session", SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("id"))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{test, App};
+    use serde_json::Value;
+
+    /// # Ensure the served specification matches the committed `openapi.json`
+    ///
+    /// Run the tests with `UPDATE_SNAPSHOTS=1` to update `openapi.json`, once a change to the API is intended.
+    #[actix_web::test]
+    async fn specification_matches_snapshot() {
+        let path: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/openapi.json");
+
+        let app = test::init_service(App::new().service(super::specification)).await;
+        let request = test::TestRequest::get().uri("/api/v1/openapi.json").to_request();
+        let served: Value = test::call_and_read_body_json(&app, request).await;
+
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            let specification: String = serde_json::to_string_pretty(&served).expect("Failed to serialise the specification.");
+            std::fs::write(path, specification + "\n").expect("Failed to update `openapi.json`.");
+        }
+
+        let snapshot: Value = serde_json::from_str(&std::fs::read_to_string(path).expect("Failed to read `openapi.json`."))
+            .expect("Failed to parse `openapi.json`.");
+
+        assert_eq!(
+            served, snapshot,
+            "The OpenAPI specification has drifted from `openapi.json`. Run the tests with `UPDATE_SNAPSHOTS=1` if the change is intended."
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    error::PayloadError,
+    http::header,
+    web::{Bytes, BytesMut},
+    Error, HttpMessage, HttpResponse,
+};
+use futures_util::{
+    future::{ready, LocalBoxFuture, Ready},
+    stream::{self, StreamExt},
+};
+use std::rc::Rc;
+
+/// # Request Body Limit
+///
+/// Rejects requests with a body larger than the limit with a 413 status. Server functions read their bodies without a limit
+/// of their own, so this applies to every route rather than relying on the `PayloadConfig` of each extractor.
+///
+/// ## Fields
+/// * `0` - The largest request body to accept, in bytes
+pub struct PayloadLimit(pub usize);
+
+impl<S, B> Transform<S, ServiceRequest> for PayloadLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = PayloadLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(PayloadLimitMiddleware {
+            service: Rc::new(service),
+            max_size: self.0,
+        }))
+    }
+}
+
+/// # Request Body Limit Middleware
+///
+/// ## Fields
+/// * `service` - The service handling the request
+/// * `max_size` - The largest request body to accept, in bytes
+pub struct PayloadLimitMiddleware<S> {
+    service: Rc<S>,
+    max_size: usize,
+}
+
+impl<S, B> Service<ServiceRequest> for PayloadLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service: Rc<S> = Rc::clone(&self.service);
+        let max_size: usize = self.max_size;
+
+        Box::pin(async move {
+            let content_length: Option<usize> = req
+                .headers()
+                .get(header::CONTENT_LENGTH)
+                .and_then(|length| length.to_str().ok())
+                .and_then(|length| length.parse::<usize>().ok());
+
+            // Reject bodies which are too large up front, or else as soon as they grow too large while being read
+            let mut is_too_large: bool = content_length.is_some_and(|length| length > max_size);
+            if !is_too_large && content_length.is_none() {
+                let mut payload: Payload = req.take_payload();
+                let mut body: BytesMut = BytesMut::new();
+
+                while let Some(chunk) = payload.next().await {
+                    let chunk = chunk?;
+                    if body.len() + chunk.len() > max_size {
+                        is_too_large = true;
+                        break;
+                    }
+                    body.extend_from_slice(&chunk);
+                }
+
+                req.set_payload(Payload::from(stream::once(ready(Ok::<Bytes, PayloadError>(body.freeze()))).boxed_local()));
+            }
+
+            if is_too_large {
+                let response = HttpResponse::PayloadTooLarge()
+                    .body(format!("The request body can't be larger than {max_size} bytes."))
+                    .map_into_right_body();
+
+                return Ok(req.into_response(response));
+            }
+
+            service.call(req).await.map(ServiceResponse::map_into_left_body)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{http::StatusCode, test, web, App};
+
+    // ## Get the payload limit, from a configuration with small content size limits
+    fn configure() -> usize {
+        super::super::configure().payload_limit()
+    }
+
+    // ## Post a body of the given size to a route of an app limited to the configured payload size
+    //
+    // ### Fields
+    // * `uri` - The route to post to
+    // * `size` - The size of the body, in bytes
+    // * `has_length` - Whether the request states the length of its body
+    async fn post(uri: &str, size: usize, has_length: bool) -> StatusCode {
+        let max_size: usize = configure();
+        let app = test::init_service(
+            App::new()
+                .service(super::super::rest::scope())
+                .route("/api/v1/views/new", leptos_actix::handle_server_fns())
+                .route("/api/v1/echo", web::post().to(|body: web::Bytes| async move { body.len().to_string() }))
+                .wrap(super::PayloadLimit(max_size)),
+        )
+        .await;
+        let mut request = test::TestRequest::post()
+            .uri(uri)
+            .insert_header(("content-type", "application/json"))
+            .set_payload(vec![b' '; size]);
+        if has_length {
+            request = request.insert_header(("content-length", size));
+        }
+
+        test::call_service(&app, request.to_request()).await.status()
+    }
+
+    #[actix_web::test]
+    async fn server_function_rejects_large_bodies() {
+        assert_eq!(post("/api/v1/views/new", configure() + 1, true).await, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(post("/api/v1/views/new", configure() + 1, false).await, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[actix_web::test]
+    async fn rest_api_rejects_large_bodies() {
+        assert_eq!(post("/api/v1/rest/views", configure() + 1, true).await, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(post("/api/v1/rest/views", configure() + 1, false).await, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[actix_web::test]
+    async fn bodies_within_the_limit_are_read() {
+        assert_eq!(post("/api/v1/echo", configure(), true).await, StatusCode::OK);
+        assert_eq!(post("/api/v1/echo", configure(), false).await, StatusCode::OK);
+    }
+}
@@ -0,0 +1,249 @@
+use actix_web::{
+    delete, get,
+    http::{header, StatusCode},
+    patch, post,
+    web::{self, Json, Path},
+    HttpRequest, HttpResponse, ResponseError, Scope,
+};
+use perse_data::{
+    config::Configuration,
+    users::schema::{User, UserPermissions},
+    views::schema::{NewView, View as PerseView, ViewPatch, ViewUpdate},
+    Database, PerseApiRequests, PerseDatabaseModels,
+};
+use perse_utils::results::{ErrorTypes, PerseError, SuccessResponse};
+use perse_view::auth::authorise_request;
+use serde_json::Value;
+use std::fmt;
+
+/// # REST API
+///
+/// JSON endpoints for Views, alongside the server functions used by the admin area.
+/// Requests are authenticated with the session or an API Token, in the same way as the server functions.
+/// Request bodies can be as large as the configured content size limits allow.
+///
+/// ## Returns
+/// * `Scope` - The REST API endpoints, under `/api/v1/rest`
+pub fn scope() -> Scope {
+    web::scope("/api/v1/rest")
+        // describe malformed request bodies with the same errors as the rest of the API
+        .app_data(web::JsonConfig::default().limit(Configuration::get().payload_limit()).error_handler(|err, _req| {
+            RestError::from(PerseError::new(ErrorTypes::BadRequest, err.to_string())).into()
+        }))
+        .service(get_views)
+        .service(new_view)
+        .service(get_view)
+        .service(update_view)
+        .service(delete_view)
+}
+
+/// # List the Views
+#[utoipa::path(
+    get,
+    path = "/api/v1/rest/views",
+    tag = "Views",
+    responses(
+        (status = 200, description = "The Views", body = SuccessResponse<Vec<PerseView>>),
+        (status = 401, description = "Not signed in, or the API token is invalid", body = PerseError),
+        (status = 403, description = "Not permitted to do this", body = PerseError),
+    ),
+)]
+#[get("/views")]
+pub(crate) async fn get_views(req: HttpRequest) -> Result<HttpResponse, RestError> {
+    // Ensure the User is permitted to do this
+    authorise_request(&req, UserPermissions::ViewDrafts).await?;
+
+    let views: Vec<PerseView> = PerseView::get_all(Database::get()?).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::new(views)))
+}
+
+/// # Create a View
+#[utoipa::path(
+    post,
+    path = "/api/v1/rest/views",
+    tag = "Views",
+    request_body = NewView,
+    responses(
+        (status = 201, description = "The new View", body = SuccessResponse<PerseView>),
+        (status = 400, description = "The request is malformed", body = PerseError),
+        (status = 401, description = "Not signed in, or the API token is invalid", body = PerseError),
+        (status = 403, description = "Not permitted to do this", body = PerseError),
+        (status = 409, description = "The View conflicts with another", body = PerseError),
+        (status = 422, description = "The View is invalid", body = PerseError),
+    ),
+)]
+#[post("/views")]
+pub(crate) async fn new_view(req: HttpRequest, data: Json<NewView>) -> Result<HttpResponse, RestError> {
+    // Ensure the User is permitted to do this
+    let user: User = authorise_request(&req, UserPermissions::EditViews).await?;
+
+    // Sanitise, and run Request & Custom validation
+    let mut data: NewView = data.into_inner();
+    data.sanitise();
+    data.is_valid()?;
+
+    // Ensure the User can publish the View, unless it is saved as a draft
+    user.authorise_new_view(&data)?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await.map_err(PerseError::from)?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Determine the URL path, and create the new View
+    data.route = NewView::determine_url_path(&mut transaction, &data).await?;
+    let view: PerseView = PerseView::create(&mut transaction, &data.into()).await?;
+
+    transaction.commit().await.map_err(PerseError::from)?;
+
+    Ok(HttpResponse::Created()
+        .insert_header((header::LOCATION, format!("/api/v1/rest/views/{}", view.id.unwrap_or_default())))
+        .json(SuccessResponse::new(view)))
+}
+
+/// # Retrieve a View
+#[utoipa::path(
+    get,
+    path = "/api/v1/rest/views/{id}",
+    tag = "Views",
+    params(("id" = String, Path, description = "ID of the View")),
+    responses(
+        (status = 200, description = "The View", body = SuccessResponse<PerseView>),
+        (status = 401, description = "Not signed in, or the API token is invalid", body = PerseError),
+        (status = 403, description = "Not permitted to do this", body = PerseError),
+        (status = 404, description = "The View doesn't exist", body = PerseError),
+    ),
+)]
+#[get("/views/{id}")]
+pub(crate) async fn get_view(req: HttpRequest, id: Path<String>) -> Result<HttpResponse, RestError> {
+    // Ensure the User is permitted to do this
+    authorise_request(&req, UserPermissions::ViewDrafts).await?;
+
+    let view: PerseView = PerseView::get_by_id(Database::get()?, &id).await?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::new(view)))
+}
+
+/// # Update a View
+///
+/// The fields in the request body replace those of the View, and any other fields are left as they are.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/rest/views/{id}",
+    tag = "Views",
+    params(("id" = String, Path, description = "ID of the View")),
+    request_body(content = ViewPatch, description = "The fields to change, any of which may be left out"),
+    responses(
+        (status = 200, description = "The updated View", body = SuccessResponse<PerseView>),
+        (status = 400, description = "The request is malformed", body = PerseError),
+        (status = 401, description = "Not signed in, or the API token is invalid", body = PerseError),
+        (status = 403, description = "Not permitted to do this", body = PerseError),
+        (status = 404, description = "The View doesn't exist", body = PerseError),
+        (status = 409, description = "The View conflicts with another", body = PerseError),
+        (status = 422, description = "The View is invalid", body = PerseError),
+    ),
+)]
+#[patch("/views/{id}")]
+pub(crate) async fn update_view(req: HttpRequest, id: Path<String>, data: Json<Value>) -> Result<HttpResponse, RestError> {
+    // Ensure the User is permitted to do this
+    let user: User = authorise_request(&req, UserPermissions::EditViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await.map_err(PerseError::from)?;
+
+    // Apply the changes to the View as it is now, locking it until the changes are committed
+    let view: PerseView = PerseView::get_by_id_for_update(&mut transaction, &id).await?;
+    let mut update: Value = serde_json::to_value(ViewPatch::from(view.clone()))
+        .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to serialise the View: {err}")))?;
+    match (update.as_object_mut(), data.into_inner()) {
+        (Some(update), Value::Object(changes)) => update.extend(changes),
+        _ => Err(PerseError::new(ErrorTypes::BadRequest, "The request body must be a JSON object."))?,
+    }
+
+    // Sanitise, and run Request & Custom validation
+    let mut data: ViewUpdate = serde_json::from_value::<ViewPatch>(update)
+        .map_err(|err| PerseError::new(ErrorTypes::BadRequest, err.to_string()))?
+        .into();
+    data.sanitise();
+    data.is_valid()?;
+
+    // Ensure the User can update the View
+    user.authorise_view_update(&view, &data)?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Determine the URL path, and update the View
+    data.route = ViewUpdate::determine_url_path(&mut transaction, &id, &data).await?;
+    let view: PerseView = PerseView::update(&mut transaction, &id, &data.into()).await?;
+
+    transaction.commit().await.map_err(PerseError::from)?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::new(view)))
+}
+
+/// # Move a View to the trash
+#[utoipa::path(
+    delete,
+    path = "/api/v1/rest/views/{id}",
+    tag = "Views",
+    params(("id" = String, Path, description = "ID of the View")),
+    responses(
+        (status = 200, description = "The deleted View", body = SuccessResponse<PerseView>),
+        (status = 401, description = "Not signed in, or the API token is invalid", body = PerseError),
+        (status = 403, description = "Not permitted to do this", body = PerseError),
+    ),
+)]
+#[delete("/views/{id}")]
+pub(crate) async fn delete_view(req: HttpRequest, id: Path<String>) -> Result<HttpResponse, RestError> {
+    // Ensure the User is permitted to do this
+    let user: User = authorise_request(&req, UserPermissions::EditViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await.map_err(PerseError::from)?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    let view: PerseView = PerseView::delete(&mut transaction, &id).await?;
+
+    // Ensure the User can change the View, before the changes are committed
+    user.authorise_view_change(&view)?;
+
+    transaction.commit().await.map_err(PerseError::from)?;
+
+    Ok(HttpResponse::Ok().json(SuccessResponse::new(view)))
+}
+
+// ## An error returned by the REST API
+//
+// Responds with the status code of the error type, and the error as the JSON body.
+//
+// ### Fields
+// * `0` - The error
+#[derive(Debug)]
+struct RestError(PerseError);
+
+impl From<PerseError> for RestError {
+    fn from(err: PerseError) -> Self {
+        Self(err)
+    }
+}
+
+impl fmt::Display for RestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ResponseError for RestError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.0.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(&self.0)
+    }
+}
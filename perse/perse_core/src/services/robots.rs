@@ -0,0 +1,114 @@
+use actix_web::{error::ErrorInternalServerError, get, HttpRequest, HttpResponse};
+use perse_data::{
+    config::{Configuration, EnvironmentTypes},
+    settings::schema::SettingKeys,
+    views::schema::View as PerseView,
+    Database, DatabasePool,
+};
+
+// # Modules
+use super::base_url;
+
+/// # Robots
+///
+/// Sites outside of production are hidden from search engines entirely. Otherwise the rules overridden for the site are
+/// served, or rules keeping the system pages and unlisted Views out of search engines, followed by a link to the sitemap.
+#[get("robots.txt")]
+pub async fn robots(req: HttpRequest) -> actix_web::Result<HttpResponse> {
+    let body: String = match Configuration::get().environment {
+        EnvironmentTypes::Production => {
+            let conn = Database::get().map_err(|err| ErrorInternalServerError(format!("{err:?}")))?;
+            rules(conn, &base_url(&req)).await?
+        }
+        _ => String::from("User-agent: *\nDisallow: /\n"),
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .body(body))
+}
+
+// ## Build the rules of a production site
+//
+// ### Fields
+// * `conn` - The database connection to use
+// * `base_url` - The canonical base URL of the site
+//
+// ### Returns
+// * `actix_web::Result<String>` - The rules overridden for the site, or the default rules, followed by the sitemap
+async fn rules(conn: &DatabasePool, base_url: &str) -> actix_web::Result<String> {
+    let mut body = String::from("User-agent: *\n");
+
+    match SettingKeys::RobotsTxt.get(conn).await.map_err(|err| ErrorInternalServerError(format!("{err:?}")))? {
+        // Serve the rules overridden for the site
+        Some(rules) => body = format!("{}\n", rules.trim_end()),
+        // Keep the system pages and unlisted Views out of search engines
+        None => {
+            body += "Disallow: /p/\nDisallow: /api/\n";
+            // Anchor each path to its end, so Views nested under an unlisted View stay indexable
+            for path in PerseView::get_unlisted_paths(conn).await.map_err(|err| ErrorInternalServerError(format!("{err:?}")))? {
+                body += &format!("Disallow: /{path}$\n");
+            }
+        }
+    }
+
+    body += &format!("\nSitemap: {base_url}/sitemap.xml\n");
+
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ## Create a public View, returning its ID
+    //
+    // ### Fields
+    // * `route` - Route of the View
+    // * `visibility` - Visibility of the View
+    // * `parent_id` - ID of the View to nest the View under, if any
+    async fn create(pool: &DatabasePool, route: &str, visibility: &str, parent_id: Option<sqlx::types::Uuid>) -> sqlx::types::Uuid {
+        sqlx::query_scalar(
+            "
+            INSERT INTO views (visibility, title, route, parent_id, published_at)
+            VALUES ($1::visibility_types, $2, $2, $3, CURRENT_TIMESTAMP)
+            RETURNING id
+            ",
+        )
+        .bind(visibility)
+        .bind(route)
+        .bind(parent_id)
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create the View.")
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn unlisted_views_are_disallowed(pool: DatabasePool) {
+        let id: sqlx::types::Uuid = create(&pool, "shared", "VisibilityUnlisted", None).await;
+        create(&pool, "page", "VisibilityPublic", Some(id)).await;
+        create(&pool, "about", "VisibilityPublic", None).await;
+
+        assert_eq!(
+            rules(&pool, "https://example.com").await.expect("Failed to build the rules."),
+            "User-agent: *\nDisallow: /p/\nDisallow: /api/\nDisallow: /shared$\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn overridden_rules_replace_the_defaults(pool: DatabasePool) {
+        create(&pool, "shared", "VisibilityUnlisted", None).await;
+
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        SettingKeys::RobotsTxt
+            .set(&mut transaction, Some("User-agent: *\nDisallow: /drafts/\n\n"))
+            .await
+            .expect("Failed to override the rules.");
+        transaction.commit().await.expect("Failed to commit the transaction.");
+
+        assert_eq!(
+            rules(&pool, "https://example.com").await.expect("Failed to build the rules."),
+            "User-agent: *\nDisallow: /drafts/\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+    }
+}
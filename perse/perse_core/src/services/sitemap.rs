@@ -0,0 +1,213 @@
+use actix_web::{
+    error::{ErrorInternalServerError, ErrorNotFound},
+    get,
+    web::{Bytes, Path},
+    HttpRequest, HttpResponse,
+};
+use futures_util::{stream, StreamExt};
+use perse_data::{
+    views::schema::{View as PerseView, ViewSitemapEntry},
+    Database, DatabasePool,
+};
+
+// # Modules
+use super::{base_url, escape_xml};
+
+/// # Maximum number of URLs in a single sitemap
+const SITEMAP_LIMIT: i64 = 50_000;
+
+/// # Sitemap
+///
+/// Lists every public View, or an index of sitemap pages once there are more than a single sitemap can hold.
+#[get("sitemap.xml")]
+pub async fn sitemap(req: HttpRequest) -> actix_web::Result<HttpResponse> {
+    let conn = Database::get().map_err(|err| ErrorInternalServerError(format!("{err:?}")))?;
+    let pages: i64 = pages(conn).await?;
+
+    if pages <= 1 {
+        return urlset(&req, conn, 0);
+    }
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/xml; charset=utf-8")
+        .body(index(&base_url(&req), pages)))
+}
+
+/// # Sitemap Page
+///
+/// Lists a page of public Views, when the sitemap has been split into an index.
+#[get("sitemap-{page}.xml")]
+pub async fn sitemap_page(req: HttpRequest, page: Path<i64>) -> actix_web::Result<HttpResponse> {
+    let conn = Database::get().map_err(|err| ErrorInternalServerError(format!("{err:?}")))?;
+    let page: i64 = page.into_inner();
+
+    if page < 1 || page > pages(conn).await? {
+        Err(ErrorNotFound("The sitemap page does not exist."))?;
+    }
+
+    urlset(&req, conn, (page - 1) * SITEMAP_LIMIT)
+}
+
+// ## Determine the number of pages in the sitemap
+//
+// ### Fields
+// * `conn` - The database connection to use
+//
+// ### Returns
+// * `actix_web::Result<i64>` - The number of pages, at least one
+async fn pages(conn: &DatabasePool) -> actix_web::Result<i64> {
+    let count: i64 = PerseView::count_public(conn)
+        .await
+        .map_err(|err| ErrorInternalServerError(format!("{err:?}")))?;
+
+    Ok(((count + SITEMAP_LIMIT - 1) / SITEMAP_LIMIT).max(1))
+}
+
+// ## Index each page of the sitemap
+//
+// ### Fields
+// * `base_url` - The canonical base URL of the site
+// * `pages` - The number of pages in the sitemap
+//
+// ### Returns
+// * `String` - The sitemap index
+fn index(base_url: &str, pages: i64) -> String {
+    let base_url: String = escape_xml(base_url);
+    let mut body = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+    for page in 1..=pages {
+        body += &format!("  <sitemap><loc>{base_url}/sitemap-{page}.xml</loc></sitemap>\n");
+    }
+    body += "</sitemapindex>\n";
+
+    body
+}
+
+// ## Stream a page of public Views as a sitemap
+//
+// ### Fields
+// * `req` - The incoming request
+// * `conn` - The database connection to use
+// * `offset` - The number of Views to skip
+//
+// ### Returns
+// * `actix_web::Result<HttpResponse>` - The streamed sitemap
+fn urlset(req: &HttpRequest, conn: &'static DatabasePool, offset: i64) -> actix_web::Result<HttpResponse> {
+    let base_url: String = base_url(req);
+
+    let header = stream::once(async {
+        Ok(Bytes::from_static(
+            b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+        ))
+    });
+    let urls = PerseView::get_public(conn, offset, SITEMAP_LIMIT).map(move |entry| {
+        entry
+            .map(|entry| Bytes::from(url(&base_url, &entry)))
+            .map_err(|err| ErrorInternalServerError(format!("{err:?}")))
+    });
+    let footer = stream::once(async { Ok(Bytes::from_static(b"</urlset>\n")) });
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/xml; charset=utf-8")
+        .streaming(header.chain(urls).chain(footer)))
+}
+
+// ## Build the sitemap entry for a View
+//
+// ### Fields
+// * `base_url` - The canonical base URL of the site
+// * `entry` - The View to list
+//
+// ### Returns
+// * `String` - The `<url>` element, with the Homepage at the root URL
+fn url(base_url: &str, entry: &ViewSitemapEntry) -> String {
+    let path: &str = match entry.is_homepage {
+        true => "",
+        false => &entry.path,
+    };
+
+    format!(
+        "  <url><loc>{}</loc><lastmod>{}</lastmod></url>\n",
+        escape_xml(&format!("{base_url}/{path}")),
+        entry.updated_at.format("%Y-%m-%dT%H:%M:%SZ"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{body, test};
+
+    // ## Create a View
+    //
+    // ### Fields
+    // * `route` - Route of the View
+    // * `visibility` - Visibility of the View
+    // * `is_published` - Whether the View has been published
+    async fn create(pool: &DatabasePool, route: &str, visibility: &str, is_published: bool) {
+        sqlx::query(
+            "
+            INSERT INTO views (visibility, title, route, published_at)
+            VALUES ($1::visibility_types, $2, $2, CASE WHEN $3 THEN CURRENT_TIMESTAMP END)
+            ",
+        )
+        .bind(visibility)
+        .bind(route)
+        .bind(is_published)
+        .execute(pool)
+        .await
+        .expect("Failed to create the View.");
+    }
+
+    // ## Read a page of the sitemap
+    async fn read(pool: DatabasePool, offset: i64) -> String {
+        super::super::configure();
+        let conn: &'static DatabasePool = Box::leak(Box::new(pool));
+
+        let response = urlset(&test::TestRequest::default().to_http_request(), conn, offset).expect("Failed to build the sitemap.");
+        let body = body::to_bytes(response.into_body()).await.expect("Failed to read the sitemap.");
+        conn.close().await;
+
+        String::from_utf8(body.to_vec()).expect("The sitemap isn't valid UTF-8.")
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn sitemap_lists_only_public_views(pool: DatabasePool) {
+        create(&pool, "about", "VisibilityPublic", true).await;
+        create(&pool, "draft", "VisibilityPublic", false).await;
+        create(&pool, "shared", "VisibilityUnlisted", true).await;
+        create(&pool, "secret", "VisibilityHidden", true).await;
+
+        assert_eq!(pages(&pool).await.expect("Failed to count the sitemap pages."), 1);
+
+        let body: String = read(pool, 0).await;
+        assert!(body.contains("<loc>http://localhost:8080/about</loc>"), "{body}");
+        for route in ["draft", "shared", "secret"] {
+            assert!(!body.contains(&format!("/{route}</loc>")), "{body}");
+        }
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn large_sitemaps_are_split_into_an_index(pool: DatabasePool) {
+        sqlx::query(
+            "
+            INSERT INTO views (visibility, title, route, published_at)
+            SELECT 'VisibilityPublic', 'View ' || number, 'view-' || number, CURRENT_TIMESTAMP
+            FROM generate_series(1, $1) AS number
+            ",
+        )
+        .bind(SITEMAP_LIMIT + 1)
+        .execute(&pool)
+        .await
+        .expect("Failed to create the Views.");
+
+        let pages: i64 = pages(&pool).await.expect("Failed to count the sitemap pages.");
+        assert_eq!(pages, 2);
+
+        let index: String = index("https://example.com", pages);
+        assert!(index.contains("<loc>https://example.com/sitemap-1.xml</loc>"), "{index}");
+        assert!(index.contains("<loc>https://example.com/sitemap-2.xml</loc>"), "{index}");
+        assert!(!index.contains("sitemap-3.xml"), "{index}");
+
+        assert_eq!(read(pool, SITEMAP_LIMIT).await.matches("<url>").count(), 1);
+    }
+}
@@ -0,0 +1,4 @@
+// Rebuild when a migration is added or changed, as the migrations are embedded in the binary
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+}
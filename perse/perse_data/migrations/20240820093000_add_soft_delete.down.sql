@@ -0,0 +1,10 @@
+-- Undo: Create indexes
+DROP INDEX idx_views_deleted_at;
+
+-- Undo: Only enforce unique routes for Views that haven't been deleted
+DELETE FROM views WHERE deleted_at IS NOT NULL;
+DROP INDEX idx_views_route_active;
+ALTER TABLE views ADD CONSTRAINT views_route_key UNIQUE (route);
+
+-- Undo: Update the Views table
+ALTER TABLE views DROP COLUMN deleted_at;
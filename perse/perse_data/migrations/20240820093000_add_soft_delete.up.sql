@@ -0,0 +1,9 @@
+-- Update the Views table
+ALTER TABLE views ADD COLUMN deleted_at TIMESTAMP;
+
+-- Only enforce unique routes for Views that haven't been deleted
+ALTER TABLE views DROP CONSTRAINT views_route_key;
+CREATE UNIQUE INDEX idx_views_route_active ON views (route) WHERE deleted_at IS NULL;
+
+-- Create indexes
+CREATE INDEX idx_views_deleted_at ON views (deleted_at);
@@ -0,0 +1,9 @@
+-- Undo: Create triggers for the Views table
+DROP TRIGGER record_views_revision_on_update ON views;
+DROP TRIGGER record_views_revision_on_insert ON views;
+
+-- Undo: Create function to record a revision of a View
+DROP FUNCTION record_view_revision;
+
+-- Undo: Create the View Revisions table
+DROP TABLE view_revisions;
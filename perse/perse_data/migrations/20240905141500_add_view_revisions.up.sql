@@ -0,0 +1,50 @@
+-- Create the View Revisions table
+CREATE TABLE view_revisions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(), -- Using UUID as the primary key
+    view_id UUID NOT NULL REFERENCES views (id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    author VARCHAR(255),
+    visibility visibility_types NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    content_body TEXT,
+    content_head TEXT,
+    description VARCHAR(255),
+    route VARCHAR(255) NOT NULL
+);
+
+-- Create indexes
+CREATE INDEX idx_view_revisions_view_id ON view_revisions (view_id, created_at DESC);
+
+-- Create function to record a revision of a View, attributed to the `perse.author` setting of the transaction
+CREATE OR REPLACE FUNCTION record_view_revision()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO view_revisions (view_id, author, visibility, title, content_body, content_head, description, route)
+    VALUES (NEW.id, NULLIF(current_setting('perse.author', TRUE), ''), NEW.visibility, NEW.title, NEW.content_body, NEW.content_head, NEW.description, NEW.route);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Create triggers for the Views table
+CREATE TRIGGER record_views_revision_on_insert
+AFTER INSERT ON views
+FOR EACH ROW
+EXECUTE FUNCTION record_view_revision();
+
+CREATE TRIGGER record_views_revision_on_update
+AFTER UPDATE ON views
+FOR EACH ROW
+WHEN (
+    OLD.visibility IS DISTINCT FROM NEW.visibility
+    OR OLD.title IS DISTINCT FROM NEW.title
+    OR OLD.content_body IS DISTINCT FROM NEW.content_body
+    OR OLD.content_head IS DISTINCT FROM NEW.content_head
+    OR OLD.description IS DISTINCT FROM NEW.description
+    OR OLD.route IS DISTINCT FROM NEW.route
+)
+EXECUTE FUNCTION record_view_revision();
+
+-- Record the current state of the existing Views as their first revision
+INSERT INTO view_revisions (view_id, created_at, visibility, title, content_body, content_head, description, route)
+SELECT id, COALESCE(updated_at, created_at), visibility, title, content_body, content_head, description, route
+FROM views;
@@ -0,0 +1,8 @@
+-- Undo: Create trigger for the View Drafts table
+DROP TRIGGER update_view_drafts_updated_at ON view_drafts;
+
+-- Undo: Create the View Drafts table
+DROP TABLE view_drafts;
+
+-- Undo: Update the Views table
+ALTER TABLE views DROP COLUMN published_at;
@@ -0,0 +1,25 @@
+-- Update the Views table
+ALTER TABLE views ADD COLUMN published_at TIMESTAMP;
+
+-- Existing Views have already been published
+UPDATE views SET published_at = COALESCE(updated_at, created_at);
+
+-- Create the View Drafts table, holding the pending changes of a View until they are published
+CREATE TABLE view_drafts (
+    view_id UUID PRIMARY KEY REFERENCES views (id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP,
+    visibility visibility_types NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    content_body TEXT,
+    content_head TEXT,
+    description VARCHAR(255),
+    route VARCHAR(255) NOT NULL,
+    is_homepage BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+-- Create trigger for the View Drafts table
+CREATE TRIGGER update_view_drafts_updated_at
+BEFORE UPDATE ON view_drafts
+FOR EACH ROW
+EXECUTE FUNCTION update_timestamp();
@@ -0,0 +1,15 @@
+-- Undo: Create indexes
+DROP INDEX idx_views_homepage_at;
+DROP INDEX idx_views_unpublish_at;
+DROP INDEX idx_views_publish_at;
+
+-- Undo: Create the View Schedule Transitions table
+DROP TABLE view_schedule_transitions;
+
+-- Undo: Create the Schedule Transition types enum
+DROP TYPE schedule_transition_types;
+
+-- Undo: Update the Views table
+ALTER TABLE views DROP COLUMN homepage_at;
+ALTER TABLE views DROP COLUMN unpublish_at;
+ALTER TABLE views DROP COLUMN publish_at;
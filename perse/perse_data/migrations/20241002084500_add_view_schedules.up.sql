@@ -0,0 +1,26 @@
+-- Update the Views table
+ALTER TABLE views ADD COLUMN publish_at TIMESTAMP;
+ALTER TABLE views ADD COLUMN unpublish_at TIMESTAMP;
+ALTER TABLE views ADD COLUMN homepage_at TIMESTAMP;
+
+-- Create the Schedule Transition types enum
+CREATE TYPE schedule_transition_types AS ENUM (
+    'TransitionPublished',
+    'TransitionUnpublished',
+    'TransitionHomepage'
+);
+
+-- Create the View Schedule Transitions table, recording every scheduled change that has been applied to a View
+CREATE TABLE view_schedule_transitions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(), -- Using UUID as the primary key
+    view_id UUID NOT NULL REFERENCES views (id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    transition schedule_transition_types NOT NULL,
+    scheduled_for TIMESTAMP NOT NULL
+);
+
+-- Create indexes
+CREATE INDEX idx_views_publish_at ON views (publish_at) WHERE publish_at IS NOT NULL;
+CREATE INDEX idx_views_unpublish_at ON views (unpublish_at) WHERE unpublish_at IS NOT NULL;
+CREATE INDEX idx_views_homepage_at ON views (homepage_at) WHERE homepage_at IS NOT NULL;
+CREATE INDEX idx_view_schedule_transitions_view_id ON view_schedule_transitions (view_id, created_at DESC);
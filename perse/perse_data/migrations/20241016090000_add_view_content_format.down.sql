@@ -0,0 +1,32 @@
+-- Undo: Update trigger for the Views table
+DROP TRIGGER record_views_revision_on_update ON views;
+CREATE TRIGGER record_views_revision_on_update
+AFTER UPDATE ON views
+FOR EACH ROW
+WHEN (
+    OLD.visibility IS DISTINCT FROM NEW.visibility
+    OR OLD.title IS DISTINCT FROM NEW.title
+    OR OLD.content_body IS DISTINCT FROM NEW.content_body
+    OR OLD.content_head IS DISTINCT FROM NEW.content_head
+    OR OLD.description IS DISTINCT FROM NEW.description
+    OR OLD.route IS DISTINCT FROM NEW.route
+)
+EXECUTE FUNCTION record_view_revision();
+
+-- Undo: Update function to record a revision of a View
+CREATE OR REPLACE FUNCTION record_view_revision()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO view_revisions (view_id, author, visibility, title, content_body, content_head, description, route)
+    VALUES (NEW.id, NULLIF(current_setting('perse.author', TRUE), ''), NEW.visibility, NEW.title, NEW.content_body, NEW.content_head, NEW.description, NEW.route);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Undo: Update the Views, View Drafts and View Revisions tables
+ALTER TABLE view_revisions DROP COLUMN content_format;
+ALTER TABLE view_drafts DROP COLUMN content_format;
+ALTER TABLE views DROP COLUMN content_format;
+
+-- Undo: Create the Content Format types enum
+DROP TYPE content_format_types;
@@ -0,0 +1,37 @@
+-- Create the Content Format types enum
+CREATE TYPE content_format_types AS ENUM (
+    'FormatPlain',
+    'FormatMarkdown',
+    'FormatHtml'
+);
+
+-- Update the Views, View Drafts and View Revisions tables
+ALTER TABLE views ADD COLUMN content_format content_format_types NOT NULL DEFAULT 'FormatPlain';
+ALTER TABLE view_drafts ADD COLUMN content_format content_format_types NOT NULL DEFAULT 'FormatPlain';
+ALTER TABLE view_revisions ADD COLUMN content_format content_format_types NOT NULL DEFAULT 'FormatPlain';
+
+-- Update function to record a revision of a View, including its content format
+CREATE OR REPLACE FUNCTION record_view_revision()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO view_revisions (view_id, author, visibility, title, content_body, content_head, content_format, description, route)
+    VALUES (NEW.id, NULLIF(current_setting('perse.author', TRUE), ''), NEW.visibility, NEW.title, NEW.content_body, NEW.content_head, NEW.content_format, NEW.description, NEW.route);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Update trigger for the Views table, so a change of content format records a revision
+DROP TRIGGER record_views_revision_on_update ON views;
+CREATE TRIGGER record_views_revision_on_update
+AFTER UPDATE ON views
+FOR EACH ROW
+WHEN (
+    OLD.visibility IS DISTINCT FROM NEW.visibility
+    OR OLD.title IS DISTINCT FROM NEW.title
+    OR OLD.content_body IS DISTINCT FROM NEW.content_body
+    OR OLD.content_head IS DISTINCT FROM NEW.content_head
+    OR OLD.content_format IS DISTINCT FROM NEW.content_format
+    OR OLD.description IS DISTINCT FROM NEW.description
+    OR OLD.route IS DISTINCT FROM NEW.route
+)
+EXECUTE FUNCTION record_view_revision();
@@ -0,0 +1,13 @@
+-- Undo: Create function to build the full path of a View
+DROP FUNCTION view_path;
+
+-- Undo: Create indexes
+DROP INDEX idx_views_parent_id;
+
+-- Undo: Only enforce unique routes between the Views that share a parent
+DROP INDEX idx_views_parent_route_active;
+CREATE UNIQUE INDEX idx_views_route_active ON views (route) WHERE deleted_at IS NULL;
+
+-- Undo: Update the Views and View Drafts tables
+ALTER TABLE view_drafts DROP COLUMN parent_id;
+ALTER TABLE views DROP COLUMN parent_id;
@@ -0,0 +1,24 @@
+-- Update the Views and View Drafts tables
+ALTER TABLE views ADD COLUMN parent_id UUID REFERENCES views (id) ON DELETE RESTRICT;
+ALTER TABLE view_drafts ADD COLUMN parent_id UUID REFERENCES views (id) ON DELETE SET NULL;
+
+-- Only enforce unique routes between the Views that share a parent
+DROP INDEX idx_views_route_active;
+CREATE UNIQUE INDEX idx_views_parent_route_active ON views (COALESCE(parent_id, '00000000-0000-0000-0000-000000000000'), route) WHERE deleted_at IS NULL;
+
+-- Create indexes
+CREATE INDEX idx_views_parent_id ON views (parent_id);
+
+-- Create function to build the full path of a View, by walking up through its parents
+CREATE OR REPLACE FUNCTION view_path(parent UUID, segment TEXT)
+RETURNS TEXT AS $$
+    WITH RECURSIVE ancestors AS (
+        SELECT id, parent_id, route, 1 AS depth FROM views WHERE id = parent
+        UNION ALL
+        SELECT views.id, views.parent_id, views.route, ancestors.depth + 1
+        FROM views
+        JOIN ancestors ON views.id = ancestors.parent_id
+        WHERE ancestors.depth < 64
+    )
+    SELECT concat_ws('/', (SELECT string_agg(route, '/' ORDER BY depth DESC) FROM ancestors), segment);
+$$ LANGUAGE sql STABLE;
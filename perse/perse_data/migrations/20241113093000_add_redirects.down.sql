@@ -0,0 +1,9 @@
+-- Undo: Create indexes
+DROP INDEX idx_redirects_target_view_id;
+DROP INDEX idx_redirects_source;
+
+-- Undo: Create the Redirects table
+DROP TABLE redirects;
+
+-- Undo: Create the Redirect match types enum
+DROP TYPE redirect_match_types;
@@ -0,0 +1,24 @@
+-- Create the Redirect match types enum
+CREATE TYPE redirect_match_types AS ENUM (
+    'MatchExact',
+    'MatchPrefix',
+    'MatchRegex'
+);
+
+-- Create the Redirects table, sending requests for old paths on to a new path or View
+CREATE TABLE redirects (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(), -- Using UUID as the primary key
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    source TEXT NOT NULL,
+    match_type redirect_match_types NOT NULL DEFAULT 'MatchExact',
+    target_path TEXT,
+    target_view_id UUID REFERENCES views (id) ON DELETE CASCADE,
+    status_code SMALLINT NOT NULL DEFAULT 301 CHECK (status_code IN (301, 302)),
+    hits BIGINT NOT NULL DEFAULT 0,
+    -- Each Redirect targets either a path or a View, but never both
+    CHECK ((target_path IS NULL) <> (target_view_id IS NULL))
+);
+
+-- Create indexes
+CREATE UNIQUE INDEX idx_redirects_source ON redirects (match_type, source);
+CREATE INDEX idx_redirects_target_view_id ON redirects (target_view_id) WHERE target_view_id IS NOT NULL;
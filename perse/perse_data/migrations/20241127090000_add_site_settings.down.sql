@@ -0,0 +1,2 @@
+-- Undo: Create the Site Settings table
+DROP TABLE site_settings;
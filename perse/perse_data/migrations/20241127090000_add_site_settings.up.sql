@@ -0,0 +1,6 @@
+-- Create the Site Settings table, holding the overrides configured for the site
+CREATE TABLE site_settings (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,6 @@
+-- Undo: Create indexes
+DROP INDEX idx_views_tags;
+
+-- Undo: Update the Views and View Drafts tables
+ALTER TABLE view_drafts DROP COLUMN tags;
+ALTER TABLE views DROP COLUMN tags;
@@ -0,0 +1,6 @@
+-- Update the Views and View Drafts tables
+ALTER TABLE views ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE view_drafts ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+-- Create indexes
+CREATE INDEX idx_views_tags ON views USING GIN (tags);
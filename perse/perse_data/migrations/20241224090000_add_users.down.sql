@@ -0,0 +1,5 @@
+-- Undo: Create index to ensure usernames are unique, regardless of their case
+DROP INDEX idx_users_username;
+
+-- Undo: Create the Users table
+DROP TABLE users;
@@ -0,0 +1,10 @@
+-- Create the Users table, holding the accounts that can manage the site
+CREATE TABLE users (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(), -- Using UUID as the primary key
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    username TEXT NOT NULL,
+    password_hash TEXT NOT NULL
+);
+
+-- Create index to ensure usernames are unique, regardless of their case
+CREATE UNIQUE INDEX idx_users_username ON users (LOWER(username));
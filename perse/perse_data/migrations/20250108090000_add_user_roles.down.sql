@@ -0,0 +1,5 @@
+-- Undo: Add the role of each User
+ALTER TABLE users DROP COLUMN role;
+
+-- Undo: Create the User role types enum
+DROP TYPE user_role_types;
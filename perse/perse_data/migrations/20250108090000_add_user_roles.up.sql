@@ -0,0 +1,11 @@
+-- Create the User role types enum, from the least to the most privileged
+CREATE TYPE user_role_types AS ENUM (
+    'RoleViewer',
+    'RoleEditor',
+    'RolePublisher',
+    'RoleAdmin'
+);
+
+-- Add the role of each User, keeping existing Users as admins
+ALTER TABLE users ADD COLUMN role user_role_types NOT NULL DEFAULT 'RoleAdmin';
+ALTER TABLE users ALTER COLUMN role SET DEFAULT 'RoleViewer';
@@ -0,0 +1,11 @@
+-- Undo: Create index to list the tokens of a User
+DROP INDEX idx_api_tokens_user_id;
+
+-- Undo: Create index to look up tokens by the hash of their secret
+DROP INDEX idx_api_tokens_token_hash;
+
+-- Undo: Create the API Tokens table
+DROP TABLE api_tokens;
+
+-- Undo: Create the API token scope types enum
+DROP TYPE api_token_scopes;
@@ -0,0 +1,24 @@
+-- Create the API token scope types enum
+CREATE TYPE api_token_scopes AS ENUM (
+    'views:read',
+    'views:write'
+);
+
+-- Create the API Tokens table, holding the tokens Users issue for headless access to the API
+CREATE TABLE api_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(), -- Using UUID as the primary key
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    user_id UUID NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    token_prefix TEXT NOT NULL,
+    token_hash TEXT NOT NULL,
+    scopes api_token_scopes[] NOT NULL,
+    expires_at TIMESTAMP,
+    last_used_at TIMESTAMP
+);
+
+-- Create index to look up tokens by the hash of their secret
+CREATE UNIQUE INDEX idx_api_tokens_token_hash ON api_tokens (token_hash);
+
+-- Create index to list the tokens of a User
+CREATE INDEX idx_api_tokens_user_id ON api_tokens (user_id);
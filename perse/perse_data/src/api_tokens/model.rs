@@ -0,0 +1,322 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use argon2::password_hash::rand_core::{OsRng, RngCore};
+        use perse_utils::results::{ErrorTypes, PerseError};
+        use sha2::{Digest, Sha256};
+        use sqlx::{query, query_as, types::{chrono::{NaiveDate, NaiveDateTime, Utc}, Uuid}, PgPool, Postgres, Transaction};
+
+        // # Modules
+        use super::{
+            super::{
+                users::schema::{User, UserPermissions},
+                PerseApiRequests,
+            },
+            schema::{ApiToken, ApiTokenScopes, IssuedApiToken, NewApiToken, API_TOKEN_PREFIX},
+        };
+
+        impl ApiToken {
+            /// # Issue and return a new API Token for a User
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `user` - The User the API Token acts as
+            /// * `token` - The `NewApiToken` to insert into the Database
+            ///
+            /// ## Returns
+            /// * `Result<IssuedApiToken, PerseError>` - The newly issued API Token, along with its secret
+            pub async fn create(transaction: &mut Transaction<'_, Postgres>, user: &User, token: &NewApiToken) -> Result<IssuedApiToken, PerseError> {
+                // Generate a random secret, and only store its hash
+                let mut bytes = [0u8; 32];
+                OsRng.fill_bytes(&mut bytes);
+                let secret: String = format!("{API_TOKEN_PREFIX}{}", hex::encode(bytes));
+                let token_prefix: String = secret.chars().take(API_TOKEN_PREFIX.len() + 4).collect();
+
+                let data: Self = query_as!(
+                    Self,
+                    "
+                    INSERT INTO api_tokens (user_id, name, token_prefix, token_hash, scopes, expires_at)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    RETURNING
+                        id,
+                        created_at,
+                        user_id,
+                        name,
+                        token_prefix,
+                        scopes AS \"scopes: Vec<ApiTokenScopes>\",
+                        expires_at,
+                        last_used_at
+                    ",
+                    user.id,
+                    token.name,
+                    token_prefix,
+                    Self::hash_secret(&secret),
+                    &token.scopes as &[ApiTokenScopes],
+                    token.expiry()?,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to create API Token: {err}")))?;
+
+                Ok(IssuedApiToken { token: data, secret })
+            }
+
+            /// # Retrieve the collection of a User's API Tokens from the Database
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `user_id` - The ID of the User
+            ///
+            /// ## Returns
+            /// * `Result<Vec<Self>, PerseError>` - The User's API Tokens, newest first
+            pub async fn get_all_by_user(conn: &PgPool, user_id: &Uuid) -> Result<Vec<Self>, PerseError> {
+                query_as!(
+                    Self,
+                    "
+                    SELECT
+                        id,
+                        created_at,
+                        user_id,
+                        name,
+                        token_prefix,
+                        scopes AS \"scopes: Vec<ApiTokenScopes>\",
+                        expires_at,
+                        last_used_at
+                    FROM api_tokens
+                    WHERE user_id = $1
+                    ORDER BY created_at DESC
+                    ",
+                    user_id,
+                )
+                .fetch_all(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve API Tokens: {err}")))
+            }
+
+            /// # Revoke one of a User's API Tokens
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `user_id` - The ID of the User the API Token belongs to
+            /// * `id` - The ID of the API Token to revoke
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn revoke(transaction: &mut Transaction<'_, Postgres>, user_id: &Uuid, id: &str) -> Result<(), PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                let result = query!("DELETE FROM api_tokens WHERE id = $1 AND user_id = $2", id, user_id)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to revoke API Token: {err}")))?;
+
+                if result.rows_affected() == 0 {
+                    Err(PerseError::new(ErrorTypes::NotFound, "The API Token could not be revoked, as it doesn't exist."))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Authenticate an API Token with its secret, and record that it has been used
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `secret` - The secret of the API Token
+            ///
+            /// ## Returns
+            /// * `Result<(User, Self), PerseError>` - The User the API Token acts as, and the API Token
+            pub async fn authenticate(conn: &PgPool, secret: &str) -> Result<(User, Self), PerseError> {
+                let invalid = || PerseError::new(ErrorTypes::Unauthorized, "The API token is invalid or has expired.");
+
+                let token: Self = query_as!(
+                    Self,
+                    "
+                    UPDATE api_tokens SET last_used_at = CURRENT_TIMESTAMP
+                    WHERE token_hash = $1
+                    AND (expires_at IS NULL OR expires_at > CURRENT_TIMESTAMP)
+                    RETURNING
+                        id,
+                        created_at,
+                        user_id,
+                        name,
+                        token_prefix,
+                        scopes AS \"scopes: Vec<ApiTokenScopes>\",
+                        expires_at,
+                        last_used_at
+                    ",
+                    Self::hash_secret(secret),
+                )
+                .fetch_optional(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve API Token: {err}")))?
+                .ok_or_else(invalid)?;
+
+                let user: User = User::get_by_id(conn, &token.user_id).await?.ok_or_else(invalid)?;
+
+                Ok((user, token))
+            }
+
+            /// # Ensure the API Token has a permission
+            ///
+            /// ## Fields
+            /// * `self` - The API Token
+            /// * `permission` - The permission required
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - A Forbidden error, if the API Token's scopes don't include the permission
+            pub fn authorise(&self, permission: UserPermissions) -> Result<(), PerseError> {
+                match self.can(permission) {
+                    true => Ok(()),
+                    false => Err(PerseError::new(ErrorTypes::Forbidden, "The API token's scopes don't permit this.")),
+                }
+            }
+
+            // ## Hash the secret of an API Token for storage
+            //
+            // The secrets are long and random, so a fast hash is enough to keep them from being recovered.
+            //
+            // ### Fields
+            // * `secret` - The secret to hash
+            //
+            // ### Returns
+            // * `String` - The SHA-256 hash of the secret, hex encoded
+            fn hash_secret(secret: &str) -> String {
+                hex::encode(Sha256::digest(secret.as_bytes()))
+            }
+        }
+
+        impl NewApiToken {
+            /// # Parse the expiry date of the API Token
+            ///
+            /// ## Fields
+            /// * `self` - The `NewApiToken`
+            ///
+            /// ## Returns
+            /// * `Result<Option<NaiveDateTime>, PerseError>` - The start of the expiry date, or `None` if it never expires
+            pub fn expiry(&self) -> Result<Option<NaiveDateTime>, PerseError> {
+                match self.expires_at.as_deref().map(str::trim) {
+                    None | Some("") => Ok(None),
+                    Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                        .ok()
+                        .and_then(|date| date.and_hms_opt(0, 0, 0))
+                        .map(Some)
+                        .ok_or_else(|| PerseError::new(ErrorTypes::Validation, format!("The expiry date `{date}` is in an incorrect format."))),
+                }
+            }
+        }
+
+        impl PerseApiRequests for NewApiToken {
+            /// # Validate the incoming `NewApiToken` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `NewApiToken` to validate
+            ///
+            /// ## Returns
+            /// * `Result<bool, PerseError>` - Whether the `NewApiToken` is valid
+            fn is_valid(&self) -> Result<(), PerseError> {
+                use validator::Validate;
+
+                // Request validation
+                self.validate()?;
+
+                // Custom validation
+                if self.expiry()?.is_some_and(|expires_at| expires_at <= Utc::now().naive_utc()) {
+                    Err(PerseError::new(ErrorTypes::Validation, "The expiry date must be in the future."))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Sanitise the incoming `NewApiToken` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `NewApiToken` to sanitise
+            fn sanitise(&mut self) {
+                self.name = self.name.trim().to_string();
+                self.scopes.dedup();
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::users::schema::UserRoleTypes;
+
+    // ## Issue an API Token for a new User, returning its secret
+    //
+    // ### Fields
+    // * `pool` - The database connection to use
+    // * `scopes` - Scopes of the API Token
+    async fn issue(pool: &PgPool, scopes: Vec<ApiTokenScopes>) -> IssuedApiToken {
+        let user: User = query_as!(
+            User,
+            "
+            INSERT INTO users (username, password_hash, role) VALUES ('editor', '', 'RoleEditor')
+            RETURNING id, created_at, username, role AS \"role: UserRoleTypes\"
+            ",
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create the User.");
+
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        let token = NewApiToken {
+            name: "Deploys".to_string(),
+            scopes,
+            expires_at: None,
+        };
+        let issued: IssuedApiToken = ApiToken::create(&mut transaction, &user, &token).await.expect("Failed to issue the API Token.");
+        transaction.commit().await.expect("Failed to commit the transaction.");
+
+        issued
+    }
+
+    // ## Determine the type of error when authenticating with a secret
+    async fn rejection(pool: &PgPool, secret: &str) -> ErrorTypes {
+        ApiToken::authenticate(pool, secret)
+            .await
+            .expect_err("The API Token should be rejected.")
+            .error_type()
+    }
+
+    #[sqlx::test]
+    async fn valid_token_acts_as_its_user(pool: PgPool) {
+        let issued: IssuedApiToken = issue(&pool, vec![ApiTokenScopes::ViewsRead]).await;
+
+        let (user, token): (User, ApiToken) = ApiToken::authenticate(&pool, &issued.secret).await.expect("Failed to authenticate.");
+        assert_eq!(user.id, issued.token.user_id);
+        assert_eq!(user.role, UserRoleTypes::RoleEditor);
+        assert!(token.last_used_at.is_some());
+        assert!(token.authorise(UserPermissions::ViewDrafts).is_ok());
+        assert!(token.authorise(UserPermissions::EditViews).is_err());
+    }
+
+    #[sqlx::test]
+    async fn unknown_and_revoked_tokens_are_rejected(pool: PgPool) {
+        let issued: IssuedApiToken = issue(&pool, vec![ApiTokenScopes::ViewsWrite]).await;
+        assert_eq!(rejection(&pool, &format!("{}0", issued.secret)).await, ErrorTypes::Unauthorized);
+
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        ApiToken::revoke(&mut transaction, &issued.token.user_id, &issued.token.id.to_string())
+            .await
+            .expect("Failed to revoke the API Token.");
+        transaction.commit().await.expect("Failed to commit the transaction.");
+
+        assert_eq!(rejection(&pool, &issued.secret).await, ErrorTypes::Unauthorized);
+    }
+
+    #[sqlx::test]
+    async fn expired_tokens_are_rejected(pool: PgPool) {
+        let issued: IssuedApiToken = issue(&pool, vec![ApiTokenScopes::ViewsWrite]).await;
+
+        query!("UPDATE api_tokens SET expires_at = CURRENT_TIMESTAMP - INTERVAL '1 second' WHERE id = $1", issued.token.id)
+            .execute(&pool)
+            .await
+            .expect("Failed to expire the API Token.");
+
+        assert_eq!(rejection(&pool, &issued.secret).await, ErrorTypes::Unauthorized);
+    }
+}
@@ -0,0 +1,141 @@
+use parse_display::{Display, FromStr};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+// # Modules
+use super::super::users::schema::UserPermissions;
+
+/// # Prefix of the secret of every API Token
+///
+/// Makes the secrets easy to recognise, for example by secret scanners.
+pub const API_TOKEN_PREFIX: &str = "perse_";
+
+/// # "ApiToken" model
+///
+/// A personal access token, which acts as its User when sent as a `Bearer` token in the `Authorization` header.
+/// Only a hash of the secret is stored, so the secret is only shown once, when the token is issued.
+///
+/// ## Fields
+///
+/// * `id` - ID of the API Token
+/// * `created_at` - Creation date of the API Token
+/// * `user_id` - ID of the User the API Token acts as
+/// * `name` - Name of the API Token, describing what it is used for
+/// * `token_prefix` - Start of the secret, to help recognise the API Token
+/// * `scopes` - Scopes of the API Token, which limit what it is permitted to do
+/// * `expires_at` - Date the API Token expires, if ever
+/// * `last_used_at` - Date the API Token was last used, if ever
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct ApiToken {
+    pub id: uuid::Uuid,
+    #[cfg(feature = "ssr")]
+    pub created_at: sqlx::types::chrono::NaiveDateTime,
+    #[cfg(not(feature = "ssr"))]
+    pub created_at: String,
+    pub user_id: uuid::Uuid,
+    pub name: String,
+    pub token_prefix: String,
+    pub scopes: Vec<ApiTokenScopes>,
+    #[cfg(feature = "ssr")]
+    pub expires_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub expires_at: Option<String>,
+    #[cfg(feature = "ssr")]
+    pub last_used_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub last_used_at: Option<String>,
+}
+
+impl ApiToken {
+    /// # Determine whether the API Token's scopes include a permission
+    ///
+    /// The User's role still has to include the permission as well.
+    ///
+    /// ## Fields
+    /// * `self` - The API Token
+    /// * `permission` - The permission to check
+    ///
+    /// ## Returns
+    /// * `bool` - Whether any of the scopes include the permission
+    pub fn can(&self, permission: UserPermissions) -> bool {
+        self.scopes.iter().any(|scope| scope.permissions().contains(&permission))
+    }
+}
+
+/// # "ApiTokenScopes" model
+///
+/// The enum's names must match with the database type.
+#[derive(Deserialize, Serialize, PartialEq, FromStr, Display, Clone, Copy, Debug)]
+#[cfg_attr(feature = "ssr", derive(sqlx::Type))]
+#[cfg_attr(feature = "ssr", sqlx(type_name = "api_token_scopes"))]
+pub enum ApiTokenScopes {
+    /// Can see every View, including hidden and unlisted Views and their Drafts
+    #[serde(rename = "views:read")]
+    #[display("views:read")]
+    #[cfg_attr(feature = "ssr", sqlx(rename = "views:read"))]
+    ViewsRead,
+    /// Can create, edit, publish, and delete Views, and manage redirects
+    #[serde(rename = "views:write")]
+    #[display("views:write")]
+    #[cfg_attr(feature = "ssr", sqlx(rename = "views:write"))]
+    ViewsWrite,
+}
+
+impl ApiTokenScopes {
+    /// # Determine the permissions included in the scope
+    ///
+    /// Settings, Users, and API Tokens can't be managed with an API Token.
+    ///
+    /// ## Fields
+    /// * `self` - The scope
+    ///
+    /// ## Returns
+    /// * `&'static [UserPermissions]` - The permissions
+    pub fn permissions(self) -> &'static [UserPermissions] {
+        match self {
+            Self::ViewsRead => &[UserPermissions::ViewDrafts],
+            Self::ViewsWrite => &[UserPermissions::EditViews, UserPermissions::PublishViews],
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl sqlx::postgres::PgHasArrayType for ApiTokenScopes {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        sqlx::postgres::PgTypeInfo::with_name("_api_token_scopes")
+    }
+}
+
+/// # "NewApiToken" request model
+///
+/// The expiry date is in the `YYYY-MM-DD` format of a `date` input, and an empty date never expires.
+///
+/// ## Fields
+///
+/// * `name` - Name of the API Token
+/// * `scopes` - Scopes of the API Token
+/// * `expires_at` - Date the API Token expires
+#[derive(Deserialize, Serialize, Clone, Debug, Validate)]
+pub struct NewApiToken {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    #[serde(default)]
+    #[validate(length(min = 1, message = "At least one scope must be chosen."))]
+    pub scopes: Vec<ApiTokenScopes>,
+    pub expires_at: Option<String>,
+}
+
+/// # "IssuedApiToken" model
+///
+/// A newly issued API Token, along with its secret.
+///
+/// ## Fields
+///
+/// * `token` - The API Token
+/// * `secret` - The secret of the API Token, which can't be retrieved again
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+pub struct IssuedApiToken {
+    pub token: ApiToken,
+    pub secret: String,
+}
@@ -0,0 +1,170 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use once_cell::sync::OnceCell;
+        use parse_display::{Display, FromStr};
+        use std::env;
+
+        // # Modules
+        use super::content::schema::HeadElementTypes;
+
+        /// # Perse Configuration
+        pub static CONFIGURATION: OnceCell<Configuration> = OnceCell::new();
+
+        /// # Environment types
+        ///
+        /// The environment Perse is deployed to. Only production sites can be indexed by search engines.
+        #[derive(PartialEq, FromStr, Display, Clone, Copy, Default, Debug)]
+        #[display(style = "lowercase")]
+        pub enum EnvironmentTypes {
+            #[default]
+            Production,
+            Staging,
+            Development,
+        }
+
+        /// # Configuration
+        ///
+        /// ## Fields
+        /// * `environment` - The environment Perse is deployed to
+        /// * `preview_secret` - The secret used to sign Preview links
+        /// * `preview_lifetime` - The number of seconds a Preview link is valid for
+        /// * `session_secret` - The secret used to sign session cookies, at least 32 bytes long
+        /// * `admin_username` - The username of the User created when no Users exist yet
+        /// * `admin_password` - The password of the User created when no Users exist yet
+        /// * `scheduler_interval` - The number of seconds between each run of the View scheduler
+        /// * `base_url` - The canonical URL of the site, such as `https://perse.dev`, or the requested host if unset
+        /// * `content_head_allowed_tags` - The tags which can be rendered into the document head from a View's head content
+        /// * `content_html_allowed_tags` - The tags permitted in HTML body content, or the sanitiser's defaults
+        /// * `content_html_allowed_attributes` - The attributes permitted on every tag in HTML body content, or the sanitiser's defaults
+        /// * `content_html_allowed_url_schemes` - The URL schemes permitted in HTML body content, or the sanitiser's defaults
+        /// * `content_body_max_size` - The largest body content a View can have, in bytes
+        /// * `content_head_max_size` - The largest head content a View can have, in bytes
+        #[derive(Clone, Debug)]
+        pub struct Configuration {
+            pub environment: EnvironmentTypes,
+            pub preview_secret: String,
+            pub preview_lifetime: i64,
+            pub session_secret: String,
+            pub admin_username: Option<String>,
+            pub admin_password: Option<String>,
+            pub scheduler_interval: u64,
+            pub base_url: Option<String>,
+            pub content_head_allowed_tags: Vec<HeadElementTypes>,
+            pub content_html_allowed_tags: Option<Vec<String>>,
+            pub content_html_allowed_attributes: Option<Vec<String>>,
+            pub content_html_allowed_url_schemes: Option<Vec<String>>,
+            pub content_body_max_size: usize,
+            pub content_head_max_size: usize,
+        }
+
+        impl Configuration {
+            // ## Load the configuration from the environment
+            //
+            // ### Returns
+            // * `Configuration` - The configuration
+            fn from_env() -> Self {
+                dotenv::dotenv().ok();
+
+                Self {
+                    environment: env::var("PERSE_ENVIRONMENT")
+                        .map(|environment| environment
+                            .parse::<EnvironmentTypes>()
+                            .expect("The `PERSE_ENVIRONMENT` environment variable is in an incorrect format."))
+                        .unwrap_or_default(),
+                    preview_secret: env::var("PERSE_PREVIEW_SECRET")
+                        .expect("The `PERSE_PREVIEW_SECRET` environment variable is not available."),
+                    preview_lifetime: env::var("PERSE_PREVIEW_LIFETIME")
+                        .map(|lifetime| lifetime
+                            .parse::<i64>()
+                            .expect("The `PERSE_PREVIEW_LIFETIME` environment variable is in an incorrect format."))
+                        .unwrap_or(60 * 60 * 24),
+                    session_secret: env::var("PERSE_SESSION_SECRET")
+                        .map(|secret| match secret.len() >= 32 {
+                            true => secret,
+                            false => panic!("The `PERSE_SESSION_SECRET` environment variable must be at least 32 bytes long."),
+                        })
+                        .expect("The `PERSE_SESSION_SECRET` environment variable is not available."),
+                    admin_username: env::var("PERSE_ADMIN_USERNAME").ok(),
+                    admin_password: env::var("PERSE_ADMIN_PASSWORD").ok(),
+                    scheduler_interval: env::var("PERSE_SCHEDULER_INTERVAL")
+                        .map(|interval| interval
+                            .parse::<u64>()
+                            .expect("The `PERSE_SCHEDULER_INTERVAL` environment variable is in an incorrect format."))
+                        .unwrap_or(60),
+                    base_url: env::var("PERSE_BASE_URL")
+                        .ok()
+                        .map(|base_url| base_url.trim_end_matches('/').to_string()),
+                    content_head_allowed_tags: Self::list_from_env("PERSE_CONTENT_HEAD_ALLOWED_TAGS")
+                        .map(|tags| tags
+                            .iter()
+                            .map(|tag| tag
+                                .parse::<HeadElementTypes>()
+                                .expect("The `PERSE_CONTENT_HEAD_ALLOWED_TAGS` environment variable contains an unsupported tag."))
+                            .collect())
+                        .unwrap_or(vec![
+                            HeadElementTypes::Meta,
+                            HeadElementTypes::Link,
+                            HeadElementTypes::Script,
+                            HeadElementTypes::Style,
+                        ]),
+                    content_html_allowed_tags: Self::list_from_env("PERSE_CONTENT_HTML_ALLOWED_TAGS"),
+                    content_html_allowed_attributes: Self::list_from_env("PERSE_CONTENT_HTML_ALLOWED_ATTRIBUTES"),
+                    content_html_allowed_url_schemes: Self::list_from_env("PERSE_CONTENT_HTML_ALLOWED_URL_SCHEMES"),
+                    content_body_max_size: env::var("PERSE_CONTENT_BODY_MAX_SIZE")
+                        .map(|size| size
+                            .parse::<usize>()
+                            .expect("The `PERSE_CONTENT_BODY_MAX_SIZE` environment variable is in an incorrect format."))
+                        .unwrap_or(5 * 1024 * 1024),
+                    content_head_max_size: env::var("PERSE_CONTENT_HEAD_MAX_SIZE")
+                        .map(|size| size
+                            .parse::<usize>()
+                            .expect("The `PERSE_CONTENT_HEAD_MAX_SIZE` environment variable is in an incorrect format."))
+                        .unwrap_or(1024 * 1024),
+                }
+            }
+
+            // ## Get the largest request body to accept
+            //
+            // Form encoding can expand content to three times its size, and room is left for the other fields of a View.
+            //
+            // ### Returns
+            // * `usize` - The largest request body to accept, in bytes
+            pub fn payload_limit(&self) -> usize {
+                3 * (self.content_body_max_size + self.content_head_max_size) + 64 * 1024
+            }
+
+            // ## Load a comma separated list from the environment
+            //
+            // ### Fields
+            // * `name` - The name of the environment variable
+            //
+            // ### Returns
+            // * `Option<Vec<String>>` - The items of the list, if the environment variable is available
+            fn list_from_env(name: &str) -> Option<Vec<String>> {
+                env::var(name).ok().map(|list| {
+                    list.split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+            }
+
+            // ## Initialise and return a reference to the configuration
+            //
+            // ### Returns
+            // * `&'static Configuration` - A reference to the configuration
+            pub fn setup() -> &'static Configuration {
+                Self::get()
+            }
+
+            // ## Get the configuration, loading it from the environment on first use
+            //
+            // ### Returns
+            // * `&'static Configuration` - A reference to the configuration
+            pub fn get() -> &'static Configuration {
+                CONFIGURATION.get_or_init(Self::from_env)
+            }
+        }
+    }
+}
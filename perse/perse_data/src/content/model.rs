@@ -0,0 +1,409 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use once_cell::sync::Lazy;
+        use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+        use scraper::{ElementRef, Html};
+        use sqlx::types::{chrono::NaiveDateTime, Uuid};
+        use std::{
+            collections::{HashMap, HashSet},
+            sync::Mutex,
+        };
+        use leptos::logging::warn;
+
+        // # Modules
+        use super::schema::{HeadElement, HeadElementTypes, RenderedBody};
+        use crate::{config::Configuration, views::schema::ViewContentFormatTypes};
+
+        /// # Rendered Body cache
+        ///
+        /// The latest rendered body of each View, along with the last updated date it was rendered from.
+        static RENDERED_BODIES: Lazy<Mutex<HashMap<Uuid, (NaiveDateTime, RenderedBody)>>> = Lazy::new(Default::default);
+
+        /// # HTML sanitiser
+        ///
+        /// Built from the configured policy.
+        static HTML_SANITISER: Lazy<ammonia::Builder<'static>> = Lazy::new(|| {
+            let configuration: &'static Configuration = Configuration::get();
+
+            RenderedBody::sanitiser(
+                configuration.content_html_allowed_tags.as_deref(),
+                configuration.content_html_allowed_attributes.as_deref(),
+                configuration.content_html_allowed_url_schemes.as_deref(),
+            )
+        });
+
+        impl HeadElement {
+            /// # Parse the permitted elements from a View's head content
+            ///
+            /// Elements which aren't in the allowlist, or which aren't at the top level of the content, are dropped.
+            /// Attributes which aren't permitted on the tag, and `javascript:` URLs, are dropped.
+            ///
+            /// ## Fields
+            /// * `content_head` - The head content to parse
+            /// * `allowed` - The tags which are permitted
+            ///
+            /// ## Returns
+            /// * `Vec<Self>` - The permitted elements, in the order they appear
+            pub fn parse(content_head: &str, allowed: &[HeadElementTypes]) -> Vec<Self> {
+                let fragment: Html = Html::parse_fragment(content_head);
+
+                fragment
+                    .root_element()
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter_map(|element| {
+                        // Only keep the allowed tags
+                        let tag: HeadElementTypes = element.value().name().parse().ok()?;
+                        if !allowed.contains(&tag) {
+                            return None;
+                        }
+
+                        // Only keep the permitted attributes
+                        let attributes: Vec<(String, String)> = element
+                            .value()
+                            .attrs()
+                            .filter_map(|(name, value)| {
+                                let name: &str = tag.attribute(name)?;
+                                (!Self::is_script_url(name, value)).then(|| (name.to_string(), value.to_string()))
+                            })
+                            .collect();
+
+                        // Keep the inline content of scripts and styles
+                        let content: Option<String> = tag
+                            .has_content()
+                            .then(|| element.text().collect::<String>())
+                            .filter(|content| !content.trim().is_empty());
+
+                        // Scripts must have a source or content to be rendered
+                        if tag == HeadElementTypes::Script
+                            && content.is_none()
+                            && !attributes.iter().any(|(name, _)| name == "src")
+                        {
+                            return None;
+                        }
+
+                        Some(Self { tag, attributes, content })
+                    })
+                    .collect()
+            }
+
+            // ## Check whether an attribute is a URL using the `javascript:` scheme
+            //
+            // ### Fields
+            // * `name` - The name of the attribute
+            // * `value` - The value of the attribute
+            //
+            // ### Returns
+            // * `bool` - Whether the attribute is a `javascript:` URL
+            fn is_script_url(name: &str, value: &str) -> bool {
+                matches!(name, "href" | "src")
+                    && value
+                        .chars()
+                        .filter(|character| !character.is_whitespace() && !character.is_control())
+                        .collect::<String>()
+                        .to_lowercase()
+                        .starts_with("javascript:")
+            }
+        }
+
+        impl RenderedBody {
+            /// # Render a View's body content in its format
+            ///
+            /// HTML is sanitised again, in case it was saved before the current policy.
+            ///
+            /// ## Fields
+            /// * `content_body` - The body content to render
+            /// * `format` - The format of the body content
+            ///
+            /// ## Returns
+            /// * `Self` - The rendered body
+            pub fn render(content_body: &str, format: ViewContentFormatTypes) -> Self {
+                Self::render_with(content_body, format, &HTML_SANITISER)
+            }
+
+            /// # Render a View's body content, reusing the previous render if the View hasn't been updated since
+            ///
+            /// ## Fields
+            /// * `id` - The ID of the View
+            /// * `updated_at` - The last updated date of the View
+            /// * `content_body` - The body content to render
+            /// * `format` - The format of the body content
+            ///
+            /// ## Returns
+            /// * `Self` - The rendered body
+            pub fn render_cached(id: &Uuid, updated_at: &NaiveDateTime, content_body: &str, format: ViewContentFormatTypes) -> Self {
+                // A poisoned cache only holds complete renders, so it's safe to keep using
+                let mut cache = RENDERED_BODIES.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+                match cache.get(id) {
+                    Some((rendered_at, body)) if rendered_at == updated_at => body.clone(),
+                    _ => {
+                        let body: Self = Self::render(content_body, format);
+                        cache.insert(*id, (*updated_at, body.clone()));
+
+                        body
+                    }
+                }
+            }
+
+            /// # Sanitise HTML body content using the configured policy
+            ///
+            /// ## Fields
+            /// * `html` - The HTML to sanitise
+            ///
+            /// ## Returns
+            /// * `String` - The sanitised HTML
+            pub fn sanitise(html: &str) -> String {
+                HTML_SANITISER.clean(html).to_string()
+            }
+
+            // ## Render a View's body content in its format, using the given sanitiser
+            //
+            // Markdown links and images can still carry unsafe URLs, so the rendered HTML is sanitised too.
+            //
+            // ### Fields
+            // * `content_body` - The body content to render
+            // * `format` - The format of the body content
+            // * `sanitiser` - The sanitiser to clean HTML with
+            //
+            // ### Returns
+            // * `Self` - The rendered body
+            fn render_with(content_body: &str, format: ViewContentFormatTypes, sanitiser: &ammonia::Builder) -> Self {
+                match format {
+                    ViewContentFormatTypes::FormatPlain => Self::Text(content_body.to_string()),
+                    ViewContentFormatTypes::FormatMarkdown => {
+                        Self::Html(sanitiser.clean(&Self::render_markdown(content_body)).to_string())
+                    }
+                    ViewContentFormatTypes::FormatHtml => Self::Html(sanitiser.clean(content_body).to_string()),
+                }
+            }
+
+            // ## Build an HTML sanitiser from a policy
+            //
+            // Scripts, styles, event handlers and `javascript:` and `vbscript:` URLs are never permitted.
+            //
+            // ### Fields
+            // * `tags` - The tags permitted, or the sanitiser's defaults
+            // * `attributes` - The attributes permitted on every tag, or the sanitiser's defaults
+            // * `schemes` - The URL schemes permitted, or the sanitiser's defaults
+            //
+            // ### Returns
+            // * `ammonia::Builder` - The sanitiser
+            fn sanitiser<'a>(
+                tags: Option<&'a [String]>,
+                attributes: Option<&'a [String]>,
+                schemes: Option<&'a [String]>,
+            ) -> ammonia::Builder<'a> {
+                let mut sanitiser = ammonia::Builder::default();
+
+                // Keep the anchors of Markdown headings and footnotes
+                sanitiser
+                    .add_tag_attributes("div", &["id"])
+                    .add_tag_attributes("h1", &["id"])
+                    .add_tag_attributes("h2", &["id"])
+                    .add_tag_attributes("h3", &["id"])
+                    .add_tag_attributes("h4", &["id"])
+                    .add_tag_attributes("h5", &["id"])
+                    .add_tag_attributes("h6", &["id"]);
+
+                if let Some(tags) = tags {
+                    sanitiser.tags(
+                        tags.iter()
+                            .map(String::as_str)
+                            .filter(|tag| !tag.eq_ignore_ascii_case("script") && !tag.eq_ignore_ascii_case("style"))
+                            .collect(),
+                    );
+                }
+
+                if let Some(attributes) = attributes {
+                    sanitiser.generic_attributes(
+                        attributes
+                            .iter()
+                            .map(String::as_str)
+                            .filter(|attribute| !attribute.to_ascii_lowercase().starts_with("on"))
+                            .filter(|attribute| {
+                                // The sanitiser sets `rel` on links itself, and can't be given it as well
+                                let is_rel: bool = attribute.eq_ignore_ascii_case("rel");
+                                if is_rel {
+                                    warn!("The `rel` attribute can't be allowed in HTML body content, so it is ignored.");
+                                }
+
+                                !is_rel
+                            })
+                            .collect(),
+                    );
+                }
+
+                if let Some(schemes) = schemes {
+                    sanitiser.url_schemes(
+                        schemes
+                            .iter()
+                            .map(String::as_str)
+                            .filter(|scheme| !scheme.eq_ignore_ascii_case("javascript") && !scheme.eq_ignore_ascii_case("vbscript"))
+                            .collect(),
+                    );
+                }
+
+                sanitiser
+            }
+
+            // ## Render Markdown as HTML
+            //
+            // CommonMark is extended with tables, footnotes and heading attributes, and every heading is given an anchor.
+            // Raw HTML isn't permitted in Markdown, so it is escaped.
+            //
+            // ### Fields
+            // * `markdown` - The Markdown to render
+            //
+            // ### Returns
+            // * `String` - The rendered HTML
+            fn render_markdown(markdown: &str) -> String {
+                let options: Options = Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES | Options::ENABLE_HEADING_ATTRIBUTES;
+                let mut events: Vec<Event> = Parser::new_ext(markdown, options)
+                    .map(|event| match event {
+                        Event::Html(markup) | Event::InlineHtml(markup) => Event::Text(markup),
+                        event => event,
+                    })
+                    .collect();
+
+                // Give every heading without an ID a unique anchor, based on its text
+                let mut anchors: HashSet<String> = HashSet::new();
+                for index in 0..events.len() {
+                    if let Event::Start(Tag::Heading { id: None, .. }) = events[index] {
+                        let text: String = events[index..]
+                            .iter()
+                            .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+                            .filter_map(|event| match event {
+                                Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                                _ => None,
+                            })
+                            .collect();
+                        let anchor: String = Self::heading_anchor(&text, &mut anchors);
+
+                        if let Event::Start(Tag::Heading { id, .. }) = &mut events[index] {
+                            *id = Some(CowStr::from(anchor));
+                        }
+                    } else if let Event::Start(Tag::Heading { id: Some(id), .. }) = &events[index] {
+                        anchors.insert(id.to_string());
+                    }
+                }
+
+                let mut rendered: String = String::with_capacity(markdown.len() * 3 / 2);
+                html::push_html(&mut rendered, events.into_iter());
+
+                rendered
+            }
+
+            // ## Create a unique anchor for a heading
+            //
+            // ### Fields
+            // * `text` - The text of the heading
+            // * `anchors` - The anchors already in use
+            //
+            // ### Returns
+            // * `String` - The anchor
+            fn heading_anchor(text: &str, anchors: &mut HashSet<String>) -> String {
+                let base: String = text
+                    .to_lowercase()
+                    .split(|character: char| !character.is_alphanumeric())
+                    .filter(|word| !word.is_empty())
+                    .collect::<Vec<&str>>()
+                    .join("-");
+                let base: String = if base.is_empty() { "section".to_string() } else { base };
+
+                let mut anchor: String = base.clone();
+                let mut suffix: usize = 1;
+                while !anchors.insert(anchor.clone()) {
+                    anchor = format!("{base}-{suffix}");
+                    suffix += 1;
+                }
+
+                anchor
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    // ## Sanitise HTML using a policy which tries to allow unsafe content
+    fn permissive(html: &str) -> String {
+        let tags: Vec<String> = ["p", "a", "img", "script", "style"].map(String::from).to_vec();
+        let attributes: Vec<String> = ["title", "onclick", "OnError", "rel"].map(String::from).to_vec();
+        let schemes: Vec<String> = ["https", "javascript", "VBScript"].map(String::from).to_vec();
+        let sanitiser: ammonia::Builder = RenderedBody::sanitiser(Some(&tags), Some(&attributes), Some(&schemes));
+
+        match RenderedBody::render_with(html, ViewContentFormatTypes::FormatHtml, &sanitiser) {
+            RenderedBody::Html(html) => html,
+            RenderedBody::Text(text) => panic!("HTML rendered as text: {text}"),
+        }
+    }
+
+    // ## Render Markdown using the default sanitiser policy
+    fn markdown(content_body: &str) -> String {
+        match RenderedBody::render_with(content_body, ViewContentFormatTypes::FormatMarkdown, &RenderedBody::sanitiser(None, None, None)) {
+            RenderedBody::Html(html) => html,
+            RenderedBody::Text(text) => panic!("Markdown rendered as text: {text}"),
+        }
+    }
+
+    #[test]
+    fn markdown_strips_script_links() {
+        let html: String = markdown("[x](javascript:alert(1))");
+
+        assert!(!html.contains("javascript:"), "{html}");
+        assert!(html.contains(">x</a>"), "{html}");
+    }
+
+    #[test]
+    fn markdown_strips_script_images() {
+        let html: String = markdown("![x](javascript:alert(1))");
+
+        assert!(!html.contains("javascript:"), "{html}");
+    }
+
+    #[test]
+    fn markdown_keeps_heading_anchors() {
+        assert_eq!(markdown("# Hello World"), "<h1 id=\"hello-world\">Hello World</h1>\n");
+    }
+
+    #[test]
+    fn markdown_keeps_safe_links() {
+        assert!(markdown("[x](https://example.com)").contains("href=\"https://example.com\""));
+    }
+
+    #[test]
+    fn policy_never_allows_scripts_or_styles() {
+        assert_eq!(permissive("<p>Hi</p><script>alert(1)</script><style>p { color: red; }</style>"), "<p>Hi</p>");
+    }
+
+    #[test]
+    fn policy_never_allows_event_handlers() {
+        assert_eq!(permissive("<p title=\"Hi\" onclick=\"alert(1)\" onerror=\"alert(1)\">Hi</p>"), "<p title=\"Hi\">Hi</p>");
+    }
+
+    #[test]
+    fn policy_never_allows_script_urls() {
+        let html: String = permissive("<a href=\"javascript:alert(1)\">x</a><a href=\"vbscript:msgbox(1)\">y</a><a href=\"https://example.com\">z</a>");
+
+        assert!(!html.contains("javascript:") && !html.contains("vbscript:"), "{html}");
+        assert!(html.contains("href=\"https://example.com\""), "{html}");
+    }
+
+    #[test]
+    fn policy_ignores_rel() {
+        assert_eq!(permissive("<a href=\"https://example.com\" rel=\"me\">x</a>"), "<a href=\"https://example.com\" rel=\"noopener noreferrer\">x</a>");
+    }
+
+    #[test]
+    fn render_sanitises_saved_html() {
+        let html: &str = "<p onclick=\"alert(1)\">Hi</p><script>alert(1)</script>";
+
+        match RenderedBody::render_with(html, ViewContentFormatTypes::FormatHtml, &RenderedBody::sanitiser(None, None, None)) {
+            RenderedBody::Html(html) => assert_eq!(html, "<p>Hi</p>"),
+            RenderedBody::Text(text) => panic!("HTML rendered as text: {text}"),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use parse_display::{Display, FromStr};
+use serde::{Deserialize, Serialize};
+
+/// # "Head Element" model
+///
+/// ## Fields
+/// * `tag` - The tag of the element
+/// * `attributes` - The permitted attributes of the element, as name and value pairs
+/// * `content` - The inline content of the element, for `<script>` and `<style>` elements
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+pub struct HeadElement {
+    pub tag: HeadElementTypes,
+    pub attributes: Vec<(String, String)>,
+    pub content: Option<String>,
+}
+
+/// # Head Element types
+///
+/// The tags which can be rendered into the document head from a View's head content
+#[derive(Deserialize, Serialize, PartialEq, FromStr, Display, Clone, Copy, Debug)]
+#[display(style = "lowercase")]
+pub enum HeadElementTypes {
+    Meta,
+    Link,
+    Script,
+    Style,
+}
+
+impl HeadElementTypes {
+    /// # The attributes permitted on the tag
+    ///
+    /// Event handlers, `charset` and `http-equiv` are never permitted, as they can break or redirect the page.
+    /// The `nonce` of `<script>` and `<style>` elements is always provided by the server.
+    ///
+    /// ## Returns
+    /// * `&'static [&'static str]` - The names of the permitted attributes
+    pub fn attributes(&self) -> &'static [&'static str] {
+        match self {
+            Self::Meta => &["name", "property", "itemprop", "content"],
+            Self::Link => &[
+                "rel", "href", "type", "media", "sizes", "hreflang", "crossorigin", "integrity", "title", "as",
+            ],
+            Self::Script => &["src", "type", "async", "defer", "crossorigin", "integrity", "referrerpolicy"],
+            Self::Style => &["media", "title"],
+        }
+    }
+
+    /// # Find a permitted attribute of the tag by name
+    ///
+    /// ## Fields
+    /// * `name` - The name of the attribute
+    ///
+    /// ## Returns
+    /// * `Option<&'static str>` - The name of the attribute, if it is permitted
+    pub fn attribute(&self, name: &str) -> Option<&'static str> {
+        self.attributes()
+            .iter()
+            .find(|attribute| attribute.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// # Whether the tag has inline content
+    ///
+    /// ## Returns
+    /// * `bool` - Whether the tag has inline content
+    pub fn has_content(&self) -> bool {
+        matches!(self, Self::Script | Self::Style)
+    }
+}
+
+/// # Rendered Body types
+///
+/// A View's body content, rendered in its format.
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+pub enum RenderedBody {
+    /// Content which is rendered as a text node
+    Text(String),
+    /// Markup which is rendered as the HTML of the body
+    Html(String),
+}
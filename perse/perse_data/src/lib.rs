@@ -1,4 +1,10 @@
 // # Modules
+pub mod api_tokens;
+pub mod config;
+pub mod content;
+pub mod redirects;
+pub mod settings;
+pub mod users;
 pub mod views;
 
 cfg_if::cfg_if! {
@@ -35,12 +41,14 @@ cfg_if::cfg_if! {
                     )
                     .await;
 
-                // Check and run Database Migrations
-                // TODO: This fails with a `VersionMissing` error, they won't be run automatically for the time being
-                // sqlx::migrate!()
-                //     .run(&database)
-                //     .await
-                //     .expect("Unable to run the database migrations.");
+                // Check the Database Migrations, and run them when enabled
+                let migrate: bool = env::var("PERSE_DATABASE_MIGRATE")
+                    .map(|migrate| migrate
+                        .parse::<bool>()
+                        .expect("The `PERSE_DATABASE_MIGRATE` environment variable is in an incorrect format."))
+                    .unwrap_or(false);
+
+                Database::migrate(&database, migrate).await;
 
                 // Allocate the Database connection pool reference
                 DATABASE_POOL
@@ -54,6 +62,89 @@ cfg_if::cfg_if! {
             }
 
 
+            // ## Check the database migrations, and run the pending ones which are embedded in the binary
+            //
+            // Perse refuses to start if the database has been migrated by a newer version, that it doesn't know about.
+            // Several instances of Perse may start at once, so the database is locked while it is migrated.
+            //
+            // ### Fields
+            // * `database` - The database connection pool
+            // * `apply` - Whether to run the pending migrations, or only check the database
+            async fn migrate(database: &DatabasePool, apply: bool) {
+                use leptos::logging::log;
+                use sqlx::migrate::{AppliedMigration, Migrate, Migration, Migrator};
+
+                static MIGRATOR: Migrator = sqlx::migrate!();
+
+                // Use a dedicated connection, so the lock is released with it if migrating fails
+                let mut conn = database
+                    .acquire()
+                    .await
+                    .expect("Failed to connect to the database to check the migrations.")
+                    .detach();
+
+                // Wait for any other instance to finish migrating, using the same advisory lock as SQLx CLI
+                if apply {
+                    conn.lock().await.expect("Failed to lock the database to run the migrations.");
+                    conn.ensure_migrations_table().await.expect("Failed to create the database migrations table.");
+                }
+
+                // A database which has never been migrated by SQLx has no migrations table yet
+                let has_migrations_table: bool = sqlx::query_scalar::<_, bool>("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+                    .fetch_one(&mut conn)
+                    .await
+                    .expect("Failed to check for the database migrations table.");
+
+                let applied: Vec<AppliedMigration> = match has_migrations_table {
+                    true => conn
+                        .list_applied_migrations()
+                        .await
+                        .expect("Failed to retrieve the applied database migrations."),
+                    false => Vec::new(),
+                };
+
+                // Refuse to start with a database that is ahead of this version of Perse
+                let unknown: Vec<String> = applied
+                    .iter()
+                    .filter(|applied| !MIGRATOR.iter().any(|migration| migration.version == applied.version))
+                    .map(|applied| applied.version.to_string())
+                    .collect();
+
+                if !unknown.is_empty() {
+                    panic!(
+                        "The database has migrations this version of Perse doesn't know about ({}), it may have been migrated by a newer version.",
+                        unknown.join(", "),
+                    );
+                }
+
+                if !apply {
+                    return;
+                }
+
+                let pending: Vec<&Migration> = MIGRATOR
+                    .iter()
+                    .filter(|migration| !migration.migration_type.is_down_migration())
+                    .filter(|migration| !applied.iter().any(|applied| applied.version == migration.version))
+                    .collect();
+
+                // Run the pending migrations while the database is still locked
+                MIGRATOR
+                    .run_direct(&mut conn)
+                    .await
+                    .expect("Failed to run the database migrations.");
+
+                for migration in &pending {
+                    log!("Applied the database migration {} ({}).", migration.version, migration.description);
+                }
+
+                match MIGRATOR.iter().map(|migration| migration.version).max() {
+                    Some(version) => log!("The database is up to date, at version {version}."),
+                    None => log!("There are no database migrations to run."),
+                }
+
+                conn.unlock().await.expect("Failed to unlock the database after running the migrations.");
+            }
+
             // ## Create the initial database connection pool
             //
             // ### Fields
@@ -78,7 +169,7 @@ cfg_if::cfg_if! {
             pub fn get() -> Result<&'static DatabasePool, PerseError> {
                 DATABASE_POOL
                     .get()
-                    .ok_or(PerseError::new(ErrorTypes::InternalError, "The database connection pool could not be retrieved."))
+                    .ok_or_else(|| PerseError::new(ErrorTypes::InternalError, "The database connection pool could not be retrieved."))
             }
         }
 
@@ -92,6 +183,12 @@ cfg_if::cfg_if! {
             /// ## Returns
             /// * `Result<(), PerseError>` - A `Result` of the validation
             fn is_valid(&self) -> Result<(), PerseError>;
+
+            /// # Sanitise an incoming API request, before it is validated
+            ///
+            /// ## Fields
+            /// * `self` - The API request payload to sanitise
+            fn sanitise(&mut self) {}
         }
 
         /// # Trait for Database models
@@ -99,6 +196,9 @@ cfg_if::cfg_if! {
             /// The payload schema to create a new database entity
             type CreateRequest;
 
+            /// The payload schema to update an existing database entity
+            type UpdateRequest;
+
             /// # Insert an entity into the Database
             ///
             /// ## Fields
@@ -113,6 +213,38 @@ cfg_if::cfg_if! {
             where
                 Self: marker::Sized;
 
+            /// # Update an entity in the Database
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the database entity to update
+            /// * `record` - The updated database entity
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The `View` updated
+            fn update(
+                transaction: &mut Transaction<'_, Postgres>,
+                id: &str,
+                record: &Self::UpdateRequest,
+            ) -> impl std::future::Future<Output = Result<Self, PerseError>> + Send
+            where
+                Self: marker::Sized;
+
+            /// # Delete an entity from the Database
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the database entity to delete
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The `View` deleted
+            fn delete(
+                transaction: &mut Transaction<'_, Postgres>,
+                id: &str,
+            ) -> impl std::future::Future<Output = Result<Self, PerseError>> + Send
+            where
+                Self: marker::Sized;
+
             /// # Retrieve a database entity from the Database
             ///
             /// ## Fields
@@ -140,3 +272,40 @@ cfg_if::cfg_if! {
         }
     }
 }
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    // ## Record a migration from a newer version of Perse
+    async fn migrate_ahead(pool: &PgPool) {
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) VALUES (29990101000000, 'from the future', TRUE, '\\x00', 0)",
+        )
+        .execute(pool)
+        .await
+        .expect("Failed to record the migration.");
+    }
+
+    #[sqlx::test]
+    async fn up_to_date_database_is_accepted(pool: PgPool) {
+        Database::migrate(&pool, false).await;
+        Database::migrate(&pool, true).await;
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "doesn't know about (29990101000000)")]
+    async fn database_ahead_is_refused(pool: PgPool) {
+        migrate_ahead(&pool).await;
+
+        Database::migrate(&pool, false).await;
+    }
+
+    #[sqlx::test]
+    #[should_panic(expected = "doesn't know about (29990101000000)")]
+    async fn database_ahead_is_refused_before_migrating(pool: PgPool) {
+        migrate_ahead(&pool).await;
+
+        Database::migrate(&pool, true).await;
+    }
+}
@@ -0,0 +1,428 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use perse_utils::results::{ErrorTypes, PerseError};
+        use sqlx::{query, query_as, types::Uuid, PgPool, Postgres, Transaction};
+
+        // # Modules
+        use super::{
+            super::PerseApiRequests,
+            schema::{NewRedirect, Redirect, RedirectMatch, RedirectMatchTypes},
+        };
+
+        impl Redirect {
+            /// # Create and return a new Redirect
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `redirect` - The `NewRedirect` to insert into the Database
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The newly created Redirect
+            pub async fn create(transaction: &mut Transaction<'_, Postgres>, redirect: &NewRedirect) -> Result<Self, PerseError> {
+                // Ensure regular expressions can be used by the database, as an invalid one would break every lookup
+                if redirect.match_type == RedirectMatchTypes::MatchRegex {
+                    query!("SELECT '' ~ $1 AS matches", redirect.source)
+                        .fetch_one(&mut **transaction)
+                        .await
+                        .map_err(|err| PerseError::new(ErrorTypes::Validation, format!("The source is not a valid regular expression: {err}")))?;
+                }
+
+                query_as!(
+                    Self,
+                    "
+                    WITH created AS (
+                        INSERT INTO redirects (source, match_type, target_path, target_view_id, status_code)
+                        VALUES ($1, $2, $3, $4, $5)
+                        RETURNING *
+                    )
+                    SELECT
+                    created.id,
+                    created.created_at,
+                    created.source,
+                    created.match_type AS \"match_type: RedirectMatchTypes\",
+                    created.target_path,
+                    created.target_view_id,
+                    target_view.path AS target_view_path,
+                    created.status_code,
+                    created.hits
+                    FROM created
+                    LEFT JOIN LATERAL (
+                        SELECT view_path(parent_id, route) AS path FROM views WHERE id = created.target_view_id AND deleted_at IS NULL
+                    ) target_view ON TRUE
+                    ",
+                    redirect.source,
+                    redirect.match_type as RedirectMatchTypes,
+                    redirect.target_path,
+                    redirect.target_view_id,
+                    redirect.status_code,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| match err.as_database_error().map(|err| err.kind()) {
+                    Some(sqlx::error::ErrorKind::UniqueViolation) => PerseError::new(
+                        ErrorTypes::Conflict,
+                        "A Redirect already exists for this source.",
+                    ),
+                    _ => PerseError::new(ErrorTypes::InternalError, format!("Failed to create Redirect: {err}")),
+                })
+            }
+
+            /// # Delete a Redirect
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the Redirect to delete
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn delete(transaction: &mut Transaction<'_, Postgres>, id: &str) -> Result<(), PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                let result = query!("DELETE FROM redirects WHERE id = $1", id)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to delete Redirect: {err}")))?;
+
+                if result.rows_affected() == 0 {
+                    Err(PerseError::new(ErrorTypes::NotFound, "The Redirect could not be deleted, as it doesn't exist."))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Retrieve a collection of all Redirects from the Database
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            ///
+            /// ## Returns
+            /// * `Result<Vec<Self>, PerseError>` - A collection of all Redirects, in the order they are matched
+            pub async fn get_all(conn: &PgPool) -> Result<Vec<Self>, PerseError> {
+                query_as!(
+                    Self,
+                    "
+                    SELECT
+                    redirects.id,
+                    redirects.created_at,
+                    redirects.source,
+                    redirects.match_type AS \"match_type: RedirectMatchTypes\",
+                    redirects.target_path,
+                    redirects.target_view_id,
+                    target_view.path AS target_view_path,
+                    redirects.status_code,
+                    redirects.hits
+                    FROM redirects
+                    LEFT JOIN LATERAL (
+                        SELECT view_path(parent_id, route) AS path FROM views WHERE id = redirects.target_view_id AND deleted_at IS NULL
+                    ) target_view ON TRUE
+                    ORDER BY redirects.match_type, LENGTH(redirects.source) DESC, redirects.created_at
+                    ",
+                )
+                .fetch_all(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve all Redirects: {err}")))
+            }
+
+            /// # Find the Redirect for a requested path, counting the hit
+            ///
+            /// Exact matches are preferred, then the longest matching prefix, then the oldest matching regular expression.
+            /// Redirects to a View that is in the trash are skipped.
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `path` - The requested path, such as `docs/getting-started`
+            ///
+            /// ## Returns
+            /// * `Result<Option<RedirectMatch>, PerseError>` - Where to redirect the request to, if a Redirect matched
+            pub async fn find(conn: &PgPool, path: &str) -> Result<Option<RedirectMatch>, PerseError> {
+                let path: &str = path.trim_matches('/');
+
+                let redirect = query_as!(
+                    RedirectMatch,
+                    "
+                    WITH matched AS (
+                        SELECT id, status_code, location
+                        FROM (
+                            SELECT
+                            redirects.id,
+                            redirects.status_code,
+                            redirects.match_type,
+                            redirects.source,
+                            redirects.created_at,
+                            CASE redirects.match_type
+                                WHEN 'MatchExact' THEN COALESCE(redirects.target_path, target_view.path)
+                                WHEN 'MatchPrefix' THEN COALESCE(redirects.target_path, target_view.path) || SUBSTR($1, LENGTH(redirects.source) + 1)
+                                WHEN 'MatchRegex' THEN COALESCE(REGEXP_REPLACE($1, redirects.source, redirects.target_path), target_view.path)
+                            END AS location
+                            FROM redirects
+                            LEFT JOIN LATERAL (
+                                SELECT view_path(parent_id, route) AS path FROM views WHERE id = redirects.target_view_id AND deleted_at IS NULL
+                            ) target_view ON TRUE
+                            WHERE (redirects.match_type = 'MatchExact' AND redirects.source = $1)
+                            OR (redirects.match_type = 'MatchPrefix' AND ($1 = redirects.source OR STARTS_WITH($1, redirects.source || '/')))
+                            OR (redirects.match_type = 'MatchRegex' AND $1 ~ redirects.source)
+                        ) candidates
+                        WHERE location IS NOT NULL
+                        ORDER BY match_type, LENGTH(source) DESC, created_at
+                        LIMIT 1
+                    ),
+                    counted AS (
+                        UPDATE redirects SET hits = hits + 1 FROM matched WHERE redirects.id = matched.id
+                    )
+                    SELECT status_code AS \"status_code!\", location AS \"location!\" FROM matched
+                    ",
+                    path,
+                )
+                .fetch_optional(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to find a Redirect: {err}")))?;
+
+                Ok(redirect.map(|redirect| RedirectMatch {
+                    location: Self::location(&redirect.location),
+                    ..redirect
+                }))
+            }
+
+            // ## Build the location header of a Redirect
+            //
+            // ### Fields
+            // * `target` - The target path or URL
+            //
+            // ### Returns
+            // * `String` - The URL unchanged, or the path from the root of the site
+            fn location(target: &str) -> String {
+                match target.starts_with("http://") || target.starts_with("https://") {
+                    true => target.to_string(),
+                    false => format!("/{}", target.trim_start_matches('/')),
+                }
+            }
+
+            /// # Retrieve the current paths of a View and every View nested under it
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `view_id` - The ID of the View
+            ///
+            /// ## Returns
+            /// * `Result<Vec<(Uuid, String)>, PerseError>` - The ID and full path of each View
+            pub async fn view_paths(transaction: &mut Transaction<'_, Postgres>, view_id: &Uuid) -> Result<Vec<(Uuid, String)>, PerseError> {
+                query!(
+                    "
+                    WITH RECURSIVE nested AS (
+                        SELECT id FROM views WHERE id = $1 AND deleted_at IS NULL
+                        UNION ALL
+                        SELECT views.id
+                        FROM views
+                        JOIN nested ON views.parent_id = nested.id
+                        WHERE views.deleted_at IS NULL
+                    )
+                    SELECT views.id, view_path(views.parent_id, views.route) AS \"path!\"
+                    FROM nested
+                    JOIN views ON views.id = nested.id
+                    ",
+                    view_id,
+                )
+                .map(|row| (row.id, row.path))
+                .fetch_all(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the paths of the View: {err}")))
+            }
+
+            /// # Redirect the previous paths of published Views which have since moved
+            ///
+            /// Each moved View gets a permanent Redirect from its previous path, replacing any existing Redirect for that path.
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `previous_paths` - The ID and full path of each View, from before they were changed
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn record_moves(transaction: &mut Transaction<'_, Postgres>, previous_paths: &[(Uuid, String)]) -> Result<(), PerseError> {
+                let (ids, paths): (Vec<Uuid>, Vec<String>) = previous_paths.iter().cloned().unzip();
+
+                query!(
+                    "
+                    INSERT INTO redirects (source, match_type, target_view_id, status_code)
+                    SELECT previous.path, 'MatchExact', views.id, 301
+                    FROM UNNEST($1::UUID[], $2::TEXT[]) AS previous (id, path)
+                    JOIN views ON views.id = previous.id
+                    WHERE views.published_at IS NOT NULL AND view_path(views.parent_id, views.route) <> previous.path
+                    ON CONFLICT (match_type, source) DO UPDATE
+                    SET target_path = NULL, target_view_id = EXCLUDED.target_view_id, status_code = EXCLUDED.status_code
+                    ",
+                    &ids,
+                    &paths,
+                )
+                .execute(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to redirect the previous paths of the View: {err}")))?;
+
+                Ok(())
+            }
+        }
+
+        impl PerseApiRequests for NewRedirect {
+            /// # Validate the incoming `NewRedirect` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `NewRedirect` to validate
+            ///
+            /// ## Returns
+            /// * `Result<bool, PerseError>` - Whether the `NewRedirect` is valid
+            fn is_valid(&self) -> Result<(), PerseError> {
+                use validator::Validate;
+
+                // Request validation
+                self.validate()?;
+
+                // Custom Validation
+                if self.target_path.is_some() == self.target_view_id.is_some() {
+                    Err(PerseError::new(ErrorTypes::Validation, "A Redirect must target either a path or a View."))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Sanitise the incoming `NewRedirect` API request
+            ///
+            /// Paths are matched without their leading and trailing slashes, and an empty target path is treated as no target.
+            ///
+            /// ## Fields
+            /// * `self` - The `NewRedirect` to sanitise
+            fn sanitise(&mut self) {
+                if self.match_type != RedirectMatchTypes::MatchRegex {
+                    self.source = self.source.trim().trim_matches('/').to_string();
+                }
+
+                self.target_path = self
+                    .target_path
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|target_path| !target_path.is_empty())
+                    .map(str::to_string);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::{
+        views::schema::{View, ViewVisibilityTypes},
+        PerseDatabaseModels,
+    };
+
+    // ## Create a Redirect to a path
+    //
+    // ### Fields
+    // * `source` - The path or regular expression to match
+    // * `match_type` - How the source is matched
+    // * `target_path` - Where to redirect to
+    async fn redirect(pool: &PgPool, source: &str, match_type: RedirectMatchTypes, target_path: &str) {
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        let redirect = NewRedirect {
+            source: source.to_string(),
+            match_type,
+            target_path: Some(target_path.to_string()),
+            target_view_id: None,
+            status_code: 302,
+        };
+
+        Redirect::create(&mut transaction, &redirect).await.expect("Failed to create the Redirect.");
+        transaction.commit().await.expect("Failed to commit the transaction.");
+    }
+
+    // ## Find where a path is redirected to, if anywhere
+    async fn location(pool: &PgPool, path: &str) -> Option<String> {
+        Redirect::find(pool, path)
+            .await
+            .expect("Failed to find a Redirect.")
+            .map(|redirect| redirect.location)
+    }
+
+    // ## Create a published View, returning its ID
+    async fn create_view(pool: &PgPool, route: &str, parent_id: Option<Uuid>) -> Uuid {
+        query!(
+            "
+            INSERT INTO views (visibility, title, route, parent_id, published_at)
+            VALUES ('VisibilityPublic', $1, $1, $2, CURRENT_TIMESTAMP)
+            RETURNING id
+            ",
+            route,
+            parent_id,
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create the View.")
+        .id
+    }
+
+    // ## Change the route of a View
+    async fn change_route(pool: &PgPool, id: &Uuid, route: &str) {
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        let view = View {
+            visibility: ViewVisibilityTypes::VisibilityPublic,
+            title: route.to_string(),
+            route: route.to_string(),
+            ..View::default()
+        };
+
+        View::update(&mut transaction, &id.to_string(), &view).await.expect("Failed to update the View.");
+        transaction.commit().await.expect("Failed to commit the transaction.");
+    }
+
+    #[sqlx::test]
+    async fn exact_matches_are_preferred(pool: PgPool) {
+        redirect(&pool, "^docs/(.*)$", RedirectMatchTypes::MatchRegex, "manual/\\1").await;
+        redirect(&pool, "docs", RedirectMatchTypes::MatchPrefix, "guides").await;
+        redirect(&pool, "docs/install", RedirectMatchTypes::MatchExact, "getting-started").await;
+
+        assert_eq!(location(&pool, "/docs/install/").await.as_deref(), Some("/getting-started"));
+    }
+
+    #[sqlx::test]
+    async fn longest_prefix_is_preferred(pool: PgPool) {
+        redirect(&pool, "^docs/(.*)$", RedirectMatchTypes::MatchRegex, "manual/\\1").await;
+        redirect(&pool, "docs", RedirectMatchTypes::MatchPrefix, "guides").await;
+        redirect(&pool, "docs/api", RedirectMatchTypes::MatchPrefix, "reference").await;
+
+        assert_eq!(location(&pool, "docs/api/views").await.as_deref(), Some("/reference/views"));
+        assert_eq!(location(&pool, "docs/install").await.as_deref(), Some("/guides/install"));
+        assert_eq!(location(&pool, "docs").await.as_deref(), Some("/guides"));
+    }
+
+    #[sqlx::test]
+    async fn regular_expressions_match_last(pool: PgPool) {
+        redirect(&pool, "^docs(.*)$", RedirectMatchTypes::MatchRegex, "manual\\1").await;
+        redirect(&pool, "docs", RedirectMatchTypes::MatchPrefix, "guides").await;
+
+        // A prefix only matches whole segments of the path
+        assert_eq!(location(&pool, "docsify").await.as_deref(), Some("/manualify"));
+        assert_eq!(location(&pool, "blog").await, None);
+    }
+
+    #[sqlx::test]
+    async fn changed_routes_are_redirected(pool: PgPool) {
+        let id: Uuid = create_view(&pool, "about", None).await;
+        create_view(&pool, "team", Some(id)).await;
+
+        change_route(&pool, &id, "about-us").await;
+
+        let redirect: Option<RedirectMatch> = Redirect::find(&pool, "about").await.expect("Failed to find a Redirect.");
+        assert_eq!(redirect.map(|redirect| (redirect.status_code, redirect.location)), Some((301, "/about-us".to_string())));
+        assert_eq!(location(&pool, "about/team").await.as_deref(), Some("/about-us/team"));
+    }
+
+    #[sqlx::test]
+    async fn unchanged_routes_are_not_redirected(pool: PgPool) {
+        let id: Uuid = create_view(&pool, "about", None).await;
+
+        change_route(&pool, &id, "about").await;
+
+        assert!(Redirect::get_all(&pool).await.expect("Failed to retrieve the Redirects.").is_empty());
+    }
+}
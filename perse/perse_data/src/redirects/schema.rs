@@ -0,0 +1,92 @@
+use parse_display::{Display, FromStr};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+// # Modules
+use crate::views::schema::deserialize_optional_id;
+
+/// # "Redirect" model
+///
+/// ## Fields
+///
+/// * `id` - ID of the Redirect
+/// * `created_at` - Creation date of the Redirect
+/// * `source` - Path, path prefix, or regular expression that requests are matched against
+/// * `match_type` - How requested paths are matched against the source
+/// * `target_path` - Path or URL to redirect to, when the Redirect doesn't target a View
+/// * `target_view_id` - ID of the View to redirect to, when the Redirect doesn't target a path
+/// * `target_view_path` - Current full path of the targeted View, if it is still active
+/// * `status_code` - HTTP status code of the Redirect, either 301 or 302
+/// * `hits` - Number of requests that have been redirected
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct Redirect {
+    pub id: uuid::Uuid,
+    #[cfg(feature = "ssr")]
+    pub created_at: sqlx::types::chrono::NaiveDateTime,
+    #[cfg(not(feature = "ssr"))]
+    pub created_at: String,
+    pub source: String,
+    pub match_type: RedirectMatchTypes,
+    pub target_path: Option<String>,
+    pub target_view_id: Option<uuid::Uuid>,
+    pub target_view_path: Option<String>,
+    pub status_code: i16,
+    pub hits: i64,
+}
+
+/// # "RedirectMatchTypes" model
+///
+/// How a requested path is matched against the source of a Redirect.
+/// Exact matches are checked first, then the longest matching prefix, then regular expressions.
+#[derive(Deserialize, Serialize, PartialEq, FromStr, Display, Clone, Copy, Default, Debug)]
+#[serde(rename_all = "PascalCase")]
+#[display(style = "CamelCase")]
+#[cfg_attr(feature = "ssr", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "ssr",
+    sqlx(type_name = "redirect_match_types", rename_all = "PascalCase")
+)]
+pub enum RedirectMatchTypes {
+    #[default]
+    MatchExact,
+    MatchPrefix,
+    MatchRegex,
+}
+
+/// # "NewRedirect" request model
+///
+/// ## Fields
+///
+/// * `source` - Path, path prefix, or regular expression that requests are matched against
+/// * `match_type` - How requested paths are matched against the source
+/// * `target_path` - Path or URL to redirect to. Regular expressions can refer to their captures, such as `\1`.
+/// * `target_view_id` - ID of the View to redirect to, instead of a path
+/// * `status_code` - HTTP status code of the Redirect, either 301 or 302
+#[derive(Deserialize, Serialize, Clone, Validate, Debug)]
+pub struct NewRedirect {
+    #[validate(length(min = 1, max = 255))]
+    pub source: String,
+    #[serde(default)]
+    pub match_type: RedirectMatchTypes,
+    #[validate(length(min = 1, max = 255))]
+    pub target_path: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub target_view_id: Option<uuid::Uuid>,
+    #[validate(range(min = 301, max = 302))]
+    pub status_code: i16,
+}
+
+/// # "RedirectMatch" model
+///
+/// A Redirect that matched a requested path.
+///
+/// ## Fields
+///
+/// * `status_code` - HTTP status code of the Redirect, either 301 or 302
+/// * `location` - Path or URL the request should be redirected to
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+pub struct RedirectMatch {
+    pub status_code: i16,
+    pub location: String,
+}
@@ -0,0 +1,95 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use perse_utils::results::{ErrorTypes, PerseError};
+        use sqlx::{query, PgPool, Postgres, Transaction};
+
+        // # Modules
+        use super::{
+            super::PerseApiRequests,
+            schema::{SettingKeys, SettingUpdate},
+        };
+
+        impl SettingKeys {
+            /// # Retrieve the overridden value of a setting
+            ///
+            /// ## Fields
+            /// * `self` - The setting to retrieve
+            /// * `conn` - The database connection to use
+            ///
+            /// ## Returns
+            /// * `Result<Option<String>, PerseError>` - The value of the setting, if it has been overridden
+            pub async fn get(self, conn: &PgPool) -> Result<Option<String>, PerseError> {
+                query!("SELECT value FROM site_settings WHERE key = $1", self.to_string())
+                    .map(|row| row.value)
+                    .fetch_optional(conn)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the {self} setting: {err}")))
+            }
+
+            /// # Override the value of a setting, or remove the override
+            ///
+            /// ## Fields
+            /// * `self` - The setting to update
+            /// * `transaction` - The database transaction in use
+            /// * `value` - The new value of the setting, or `None` to remove the override
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn set(self, transaction: &mut Transaction<'_, Postgres>, value: Option<&str>) -> Result<(), PerseError> {
+                match value {
+                    Some(value) => query!(
+                        "
+                        INSERT INTO site_settings (key, value)
+                        VALUES ($1, $2)
+                        ON CONFLICT (key) DO UPDATE
+                        SET value = EXCLUDED.value, updated_at = CURRENT_TIMESTAMP
+                        ",
+                        self.to_string(),
+                        value,
+                    )
+                    .execute(&mut **transaction)
+                    .await,
+                    None => query!("DELETE FROM site_settings WHERE key = $1", self.to_string())
+                        .execute(&mut **transaction)
+                        .await,
+                }
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to update the {self} setting: {err}")))?;
+
+                Ok(())
+            }
+        }
+
+        impl PerseApiRequests for SettingUpdate {
+            /// # Validate the incoming `SettingUpdate` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `SettingUpdate` to validate
+            ///
+            /// ## Returns
+            /// * `Result<bool, PerseError>` - Whether the `SettingUpdate` is valid
+            fn is_valid(&self) -> Result<(), PerseError> {
+                use validator::Validate;
+
+                // Request validation
+                self.validate()?;
+
+                Ok(())
+            }
+
+            /// # Sanitise the incoming `SettingUpdate` API request
+            ///
+            /// An empty value is treated as removing the override.
+            ///
+            /// ## Fields
+            /// * `self` - The `SettingUpdate` to sanitise
+            fn sanitise(&mut self) {
+                self.value = self
+                    .value
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string);
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use parse_display::{Display, FromStr};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # "SettingKeys" model
+///
+/// The settings that can be overridden for the site, stored by their snake case name.
+#[derive(Deserialize, Serialize, PartialEq, FromStr, Display, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+#[display(style = "snake_case")]
+pub enum SettingKeys {
+    RobotsTxt,
+}
+
+/// # "SettingUpdate" request model
+///
+/// ## Fields
+///
+/// * `key` - The setting to update
+/// * `value` - The new value of the setting, or nothing to remove the override
+#[derive(Deserialize, Serialize, Clone, Validate, Debug)]
+pub struct SettingUpdate {
+    pub key: SettingKeys,
+    #[validate(length(min = 1, max = 65535))]
+    pub value: Option<String>,
+}
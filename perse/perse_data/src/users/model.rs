@@ -0,0 +1,504 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use argon2::{
+            password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+            Argon2,
+        };
+        use once_cell::sync::Lazy;
+        use perse_utils::results::{ErrorTypes, PerseError};
+        use sqlx::{query, query_as, types::Uuid, PgPool, Postgres, Transaction};
+        use leptos::logging::{log, warn};
+
+        // # Modules
+        use super::{
+            super::{
+                config::Configuration,
+                views::schema::{NewView, View, ViewUpdate},
+                PerseApiRequests,
+            },
+            schema::{NewUser, User, UserLogin, UserPermissions, UserRoleTypes},
+        };
+
+        // Hash verified when a username doesn't exist, so it takes as long as an incorrect password
+        static UNKNOWN_USER_HASH: Lazy<String> = Lazy::new(|| {
+            User::hash_password("unknown user").expect("The placeholder password could not be hashed.")
+        });
+
+        impl User {
+            /// # Create and return a new User
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `user` - The `NewUser` to insert into the Database, whose password is only stored as a hash
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The newly created User
+            pub async fn create(transaction: &mut Transaction<'_, Postgres>, user: &NewUser) -> Result<Self, PerseError> {
+                let password: String = user.password.clone();
+                let password_hash: String = tokio::task::spawn_blocking(move || Self::hash_password(&password))
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to hash the password: {err}")))??;
+
+                query_as!(
+                    Self,
+                    "
+                    INSERT INTO users (username, password_hash, role)
+                    VALUES ($1, $2, $3)
+                    RETURNING id, created_at, username, role AS \"role: UserRoleTypes\"
+                    ",
+                    user.username,
+                    password_hash,
+                    user.role as UserRoleTypes,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| match err.as_database_error().map(|err| err.kind()) {
+                    Some(sqlx::error::ErrorKind::UniqueViolation) => PerseError::new(
+                        ErrorTypes::Conflict,
+                        "A User already exists with this username.",
+                    ),
+                    _ => PerseError::new(ErrorTypes::InternalError, format!("Failed to create User: {err}")),
+                })
+            }
+
+            /// # Retrieve a User by its ID
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `id` - The ID of the User
+            ///
+            /// ## Returns
+            /// * `Result<Option<Self>, PerseError>` - The User, if it exists
+            pub async fn get_by_id(conn: &PgPool, id: &Uuid) -> Result<Option<Self>, PerseError> {
+                query_as!(
+                    Self,
+                    "SELECT id, created_at, username, role AS \"role: UserRoleTypes\" FROM users WHERE id = $1",
+                    id,
+                )
+                .fetch_optional(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve User: {err}")))
+            }
+
+            /// # Retrieve a collection of all Users from the Database
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            ///
+            /// ## Returns
+            /// * `Result<Vec<Self>, PerseError>` - A collection of all Users, by username
+            pub async fn get_all(conn: &PgPool) -> Result<Vec<Self>, PerseError> {
+                query_as!(
+                    Self,
+                    "SELECT id, created_at, username, role AS \"role: UserRoleTypes\" FROM users ORDER BY LOWER(username)",
+                )
+                .fetch_all(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve all Users: {err}")))
+            }
+
+            /// # Change the role of a User
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the User to change
+            /// * `role` - The new role of the User
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The updated User
+            pub async fn update_role(transaction: &mut Transaction<'_, Postgres>, id: &str, role: UserRoleTypes) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                let user: Self = query_as!(
+                    Self,
+                    "
+                    UPDATE users SET role = $2
+                    WHERE id = $1
+                    RETURNING id, created_at, username, role AS \"role: UserRoleTypes\"
+                    ",
+                    id,
+                    role as UserRoleTypes,
+                )
+                .fetch_optional(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to update User: {err}")))?
+                .ok_or_else(|| PerseError::new(ErrorTypes::NotFound, "The User could not be updated, as it doesn't exist."))?;
+
+                Self::ensure_admin_remains(transaction).await?;
+
+                Ok(user)
+            }
+
+            /// # Delete a User
+            ///
+            /// Revisions keep the username of their author.
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the User to delete
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn delete(transaction: &mut Transaction<'_, Postgres>, id: &str) -> Result<(), PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                let result = query!("DELETE FROM users WHERE id = $1", id)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to delete User: {err}")))?;
+
+                if result.rows_affected() == 0 {
+                    Err(PerseError::new(ErrorTypes::NotFound, "The User could not be deleted, as it doesn't exist."))?;
+                }
+
+                Self::ensure_admin_remains(transaction).await
+            }
+
+            // ## Ensure the site can still be managed, after a User has been changed or deleted
+            //
+            // ### Fields
+            // * `transaction` - The database transaction in use
+            //
+            // ### Returns
+            // * `Result<(), PerseError>` - A Conflict error, if no admins remain
+            async fn ensure_admin_remains(transaction: &mut Transaction<'_, Postgres>) -> Result<(), PerseError> {
+                let admins: i64 = query!(
+                    "SELECT COUNT(id) AS \"count!\" FROM users WHERE role = $1",
+                    UserRoleTypes::RoleAdmin as UserRoleTypes,
+                )
+                .map(|row| row.count)
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to count the admins: {err}")))?;
+
+                if admins == 0 {
+                    Err(PerseError::new(ErrorTypes::Conflict, "At least one User must remain an admin."))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Authenticate a User with their username and password
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `login` - The username and password to check
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The authenticated User
+            pub async fn authenticate(conn: &PgPool, login: &UserLogin) -> Result<Self, PerseError> {
+                let user = query!(
+                    "
+                    SELECT id, created_at, username, role AS \"role: UserRoleTypes\", password_hash
+                    FROM users
+                    WHERE LOWER(username) = LOWER($1)
+                    ",
+                    login.username,
+                )
+                .fetch_optional(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve User: {err}")))?;
+
+                // Verify the password off the async runtime, as hashing is deliberately slow
+                let password: String = login.password.clone();
+                let password_hash: String = user
+                    .as_ref()
+                    .map(|user| user.password_hash.clone())
+                    .unwrap_or_else(|| UNKNOWN_USER_HASH.clone());
+                let verified: bool = tokio::task::spawn_blocking(move || {
+                    PasswordHash::new(&password_hash)
+                        .is_ok_and(|hash| Argon2::default().verify_password(password.as_bytes(), &hash).is_ok())
+                })
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to verify the password: {err}")))?;
+
+                match user {
+                    Some(user) if verified => Ok(Self {
+                        id: user.id,
+                        created_at: user.created_at,
+                        username: user.username,
+                        role: user.role,
+                    }),
+                    _ => Err(PerseError::new(ErrorTypes::Unauthorized, "The username or password is incorrect.")),
+                }
+            }
+
+            /// # Create the first User from the configuration, when no Users exist yet
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            ///
+            /// ## Returns
+            /// * `Result<Option<Self>, PerseError>` - The newly created User, if one was created
+            pub async fn seed(conn: &PgPool) -> Result<Option<Self>, PerseError> {
+                let users: i64 = query!("SELECT COUNT(id) AS \"count!\" FROM users")
+                    .map(|row| row.count)
+                    .fetch_one(conn)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to count the Users: {err}")))?;
+
+                if users != 0 {
+                    return Ok(None);
+                }
+
+                let configuration: &Configuration = Configuration::get();
+                let (Some(username), Some(password)) = (&configuration.admin_username, &configuration.admin_password) else {
+                    warn!("No Users exist yet, set `PERSE_ADMIN_USERNAME` and `PERSE_ADMIN_PASSWORD` to create the first User.");
+                    return Ok(None);
+                };
+
+                let mut transaction = conn.begin().await?;
+                let user: Self = Self::create(
+                    &mut transaction,
+                    &NewUser {
+                        username: username.trim().to_string(),
+                        password: password.to_string(),
+                        role: UserRoleTypes::RoleAdmin,
+                    },
+                )
+                .await?;
+                transaction.commit().await?;
+
+                log!("Created the first User, {}.", user.username);
+
+                Ok(Some(user))
+            }
+
+            /// # Attribute the changes made in a transaction to the User
+            ///
+            /// Revisions recorded in the transaction are authored by the User.
+            ///
+            /// ## Fields
+            /// * `self` - The User making the changes
+            /// * `transaction` - The database transaction in use
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn set_author(&self, transaction: &mut Transaction<'_, Postgres>) -> Result<(), PerseError> {
+                query!("SELECT set_config('perse.author', $1, TRUE) AS author", self.username)
+                    .fetch_one(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to set the author of the changes: {err}")))?;
+
+                Ok(())
+            }
+
+            /// # Ensure the User has a permission
+            ///
+            /// ## Fields
+            /// * `self` - The User
+            /// * `permission` - The permission required
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - A Forbidden error, if the User's role doesn't include the permission
+            pub fn authorise(&self, permission: UserPermissions) -> Result<(), PerseError> {
+                match self.can(permission) {
+                    true => Ok(()),
+                    false => Err(PerseError::new(ErrorTypes::Forbidden, format!("The {} role isn't permitted to do this.", self.role))),
+                }
+            }
+
+            /// # Ensure the User can create a View
+            ///
+            /// Views which are published straight away, or become the Homepage, are visible to the public.
+            ///
+            /// ## Fields
+            /// * `self` - The User
+            /// * `view` - The `NewView` to create
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - A Forbidden error, if the User can't create the View
+            pub fn authorise_new_view(&self, view: &NewView) -> Result<(), PerseError> {
+                match view.is_draft.is_none() || view.is_homepage.is_some() {
+                    true => self.authorise(UserPermissions::PublishViews),
+                    false => self.authorise(UserPermissions::EditViews),
+                }
+            }
+
+            /// # Ensure the User can change a View
+            ///
+            /// Changes to a published View are visible to the public. Drafts are unaffected, as they aren't visible until published.
+            ///
+            /// ## Fields
+            /// * `self` - The User
+            /// * `view` - The View, before it is changed
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - A Forbidden error, if the User can't change the View
+            pub fn authorise_view_change(&self, view: &View) -> Result<(), PerseError> {
+                match view.published_at.is_some() {
+                    true => self.authorise(UserPermissions::PublishViews),
+                    false => self.authorise(UserPermissions::EditViews),
+                }
+            }
+
+            /// # Ensure the User can update a View
+            ///
+            /// ## Fields
+            /// * `self` - The User
+            /// * `view` - The View, before it is updated
+            /// * `update` - The `ViewUpdate` to apply
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - A Forbidden error, if the User can't update the View
+            pub fn authorise_view_update(&self, view: &View, update: &ViewUpdate) -> Result<(), PerseError> {
+                self.authorise_view_change(view)?;
+
+                // Changing the Homepage replaces the one visible to the public
+                if update.is_homepage.is_some() != view.is_homepage {
+                    self.authorise(UserPermissions::PublishViews)?;
+                }
+
+                Ok(())
+            }
+
+            // ## Hash a password for storage
+            //
+            // ### Fields
+            // * `password` - The password to hash
+            //
+            // ### Returns
+            // * `Result<String, PerseError>` - The Argon2 hash of the password, in PHC string format
+            fn hash_password(password: &str) -> Result<String, PerseError> {
+                Argon2::default()
+                    .hash_password(password.as_bytes(), &SaltString::generate(&mut OsRng))
+                    .map(|hash| hash.to_string())
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to hash the password: {err}")))
+            }
+        }
+
+        impl PerseApiRequests for NewUser {
+            /// # Validate the incoming `NewUser` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `NewUser` to validate
+            ///
+            /// ## Returns
+            /// * `Result<bool, PerseError>` - Whether the `NewUser` is valid
+            fn is_valid(&self) -> Result<(), PerseError> {
+                use validator::Validate;
+
+                // Request validation
+                self.validate()?;
+
+                Ok(())
+            }
+
+            /// # Sanitise the incoming `NewUser` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `NewUser` to sanitise
+            fn sanitise(&mut self) {
+                self.username = self.username.trim().to_string();
+            }
+        }
+
+        impl PerseApiRequests for UserLogin {
+            /// # Validate the incoming `UserLogin` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `UserLogin` to validate
+            ///
+            /// ## Returns
+            /// * `Result<bool, PerseError>` - Whether the `UserLogin` is valid
+            fn is_valid(&self) -> Result<(), PerseError> {
+                use validator::Validate;
+
+                // Request validation
+                self.validate()?;
+
+                Ok(())
+            }
+
+            /// # Sanitise the incoming `UserLogin` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `UserLogin` to sanitise
+            fn sanitise(&mut self) {
+                self.username = self.username.trim().to_string();
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    // ## Build a User with a role
+    fn user(role: UserRoleTypes) -> User {
+        User {
+            id: Uuid::nil(),
+            created_at: Default::default(),
+            username: format!("{role}"),
+            role,
+        }
+    }
+
+    // ## Build a View, which may have been published or be the Homepage
+    fn view(is_published: bool, is_homepage: bool) -> View {
+        View {
+            published_at: is_published.then(Default::default),
+            is_homepage,
+            ..View::default()
+        }
+    }
+
+    #[test]
+    fn roles_include_the_permissions_of_lesser_roles() {
+        use UserPermissions::*;
+        use UserRoleTypes::*;
+
+        let permitted = |role: UserRoleTypes| {
+            [ViewDrafts, ManageApiTokens, EditViews, PublishViews, ManageSettings, ManageUsers]
+                .into_iter()
+                .filter(|permission| user(role).authorise(*permission).is_ok())
+                .count()
+        };
+
+        assert_eq!(permitted(RoleViewer), 2);
+        assert_eq!(permitted(RoleEditor), 3);
+        assert_eq!(permitted(RolePublisher), 4);
+        assert_eq!(permitted(RoleAdmin), 6);
+    }
+
+    #[test]
+    fn lesser_roles_are_forbidden() {
+        let err: PerseError = user(UserRoleTypes::RoleEditor)
+            .authorise(UserPermissions::PublishViews)
+            .expect_err("An editor can't publish Views.");
+
+        assert_eq!(err.error_type(), ErrorTypes::Forbidden);
+        assert_eq!(err.message(), "The RoleEditor role isn't permitted to do this.");
+    }
+
+    #[test]
+    fn editors_can_only_update_unpublished_views() {
+        let editor: User = user(UserRoleTypes::RoleEditor);
+        let draft: View = view(false, false);
+        let published: View = view(true, false);
+
+        assert!(editor.authorise_view_update(&draft, &ViewUpdate::from(draft.clone())).is_ok());
+        assert!(editor.authorise_view_update(&published, &ViewUpdate::from(published.clone())).is_err());
+        assert!(user(UserRoleTypes::RolePublisher).authorise_view_update(&published, &ViewUpdate::from(published.clone())).is_ok());
+    }
+
+    #[test]
+    fn editors_can_not_change_the_homepage() {
+        let editor: User = user(UserRoleTypes::RoleEditor);
+        let homepage: View = view(false, true);
+        let update = ViewUpdate {
+            is_homepage: None,
+            ..ViewUpdate::from(homepage.clone())
+        };
+
+        // Neither making a View the Homepage, nor taking the Homepage away
+        assert!(editor.authorise_view_update(&view(false, false), &ViewUpdate::from(homepage.clone())).is_err());
+        assert!(editor.authorise_view_update(&homepage, &update).is_err());
+        assert!(user(UserRoleTypes::RolePublisher).authorise_view_update(&homepage, &update).is_ok());
+    }
+}
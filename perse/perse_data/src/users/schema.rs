@@ -0,0 +1,155 @@
+use parse_display::{Display, FromStr};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use validator::Validate;
+
+/// # Key of the signed in User's ID in the session
+pub const SESSION_USER_ID: &str = "user_id";
+
+/// # "User" model
+///
+/// An account that can manage the site. The password hash is never loaded into this model.
+///
+/// ## Fields
+///
+/// * `id` - ID of the User
+/// * `created_at` - Creation date of the User
+/// * `username` - Username of the User, unique regardless of case
+/// * `role` - Role of the User, which determines what they are permitted to do
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct User {
+    pub id: uuid::Uuid,
+    #[cfg(feature = "ssr")]
+    pub created_at: sqlx::types::chrono::NaiveDateTime,
+    #[cfg(not(feature = "ssr"))]
+    pub created_at: String,
+    pub username: String,
+    pub role: UserRoleTypes,
+}
+
+impl User {
+    /// # Determine whether the User has a permission
+    ///
+    /// ## Fields
+    /// * `self` - The User
+    /// * `permission` - The permission to check
+    ///
+    /// ## Returns
+    /// * `bool` - Whether the User's role includes the permission
+    pub fn can(&self, permission: UserPermissions) -> bool {
+        self.role >= permission.role()
+    }
+}
+
+/// # "UserRoleTypes" model
+///
+/// The roles of Users, from the least to the most privileged. Each role includes the permissions of the roles before it.
+#[derive(Deserialize, Serialize, PartialEq, PartialOrd, FromStr, Display, Clone, Copy, Default, Debug)]
+#[serde(rename_all = "PascalCase")]
+#[display(style = "CamelCase")]
+#[cfg_attr(feature = "ssr", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "ssr",
+    sqlx(type_name = "user_role_types", rename_all = "PascalCase")
+)]
+pub enum UserRoleTypes {
+    /// Can see every View, including hidden and unlisted Views and their Drafts
+    #[default]
+    RoleViewer,
+    /// Can also create Views, and edit Views which haven't been published
+    RoleEditor,
+    /// Can also publish Views, and change the Views that are visible to the public
+    RolePublisher,
+    /// Can also manage the settings of the site, and its Users
+    RoleAdmin,
+}
+
+/// # "UserPermissions" model
+///
+/// The actions that are restricted to certain roles.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum UserPermissions {
+    /// See every View, including hidden and unlisted Views and their Drafts
+    ViewDrafts,
+    /// Create Views, edit Views which haven't been published, and save Drafts
+    EditViews,
+    /// Publish Views, and change Views which have been published, their visibility, and the Homepage
+    PublishViews,
+    /// Change the settings of the site
+    ManageSettings,
+    /// Create, change, and delete Users
+    ManageUsers,
+    /// Issue and revoke the User's own API Tokens
+    ManageApiTokens,
+}
+
+impl UserPermissions {
+    /// # Determine the least privileged role with the permission
+    ///
+    /// ## Fields
+    /// * `self` - The permission
+    ///
+    /// ## Returns
+    /// * `UserRoleTypes` - The role
+    pub fn role(self) -> UserRoleTypes {
+        match self {
+            Self::ViewDrafts | Self::ManageApiTokens => UserRoleTypes::RoleViewer,
+            Self::EditViews => UserRoleTypes::RoleEditor,
+            Self::PublishViews => UserRoleTypes::RolePublisher,
+            Self::ManageSettings | Self::ManageUsers => UserRoleTypes::RoleAdmin,
+        }
+    }
+}
+
+/// # "NewUser" request model
+///
+/// ## Fields
+///
+/// * `username` - Username of the User
+/// * `password` - Password of the User
+/// * `role` - Role of the User
+#[derive(Deserialize, Serialize, Clone, Validate)]
+pub struct NewUser {
+    #[validate(length(min = 1, max = 255))]
+    pub username: String,
+    #[validate(length(min = 8, max = 1024, message = "The password must be between 8 and 1024 characters long."))]
+    pub password: String,
+    #[serde(default)]
+    pub role: UserRoleTypes,
+}
+
+impl fmt::Debug for NewUser {
+    // Keep the password out of the logs
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NewUser")
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .field("role", &self.role)
+            .finish()
+    }
+}
+
+/// # "UserLogin" request model
+///
+/// ## Fields
+///
+/// * `username` - Username of the User
+/// * `password` - Password of the User
+#[derive(Deserialize, Serialize, Clone, Validate)]
+pub struct UserLogin {
+    #[validate(length(min = 1, max = 255))]
+    pub username: String,
+    #[validate(length(min = 1, max = 1024, message = "The password must be at most 1024 characters long."))]
+    pub password: String,
+}
+
+impl fmt::Debug for UserLogin {
+    // Keep the password out of the logs
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserLogin")
+            .field("username", &self.username)
+            .field("password", &"[redacted]")
+            .finish()
+    }
+}
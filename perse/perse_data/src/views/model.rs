@@ -1,12 +1,23 @@
 cfg_if::cfg_if! {
     if #[cfg(feature = "ssr")] {
-        use perse_utils::results::{ErrorTypes, PerseError};
-        use sqlx::{query, query_as, types::Uuid, PgPool, Postgres, Transaction};
+        use leptos::logging::log;
+        use perse_utils::results::{ErrorTypes, FieldErrors, PerseError};
+        use futures_util::{Stream, StreamExt};
+        use sqlx::{query, query_as, query_scalar, types::Uuid, PgPool, Postgres, Transaction};
 
         // # Modules
         use super::{
-            super::{PerseApiRequests, PerseDatabaseModels},
-            schema::{NewView, View, ViewVisibilityTypes},
+            super::{
+                config::Configuration,
+                content::schema::{HeadElement, RenderedBody},
+                redirects::schema::Redirect,
+                PerseApiRequests, PerseDatabaseModels,
+            },
+            schema::{
+                homepage_error, NewView, RenderedView, View, ViewContentFormatTypes, ViewDraft, ViewRevision, ViewRevisionDiff,
+                ViewRevisionDiffTypes, ViewSchedule, ViewScheduleTransition, ViewScheduleTransitionTypes, ViewSitemapEntry,
+                ViewUpdate, ViewVisibilityTypes,
+            },
         };
 
         impl View {
@@ -16,8 +27,8 @@ cfg_if::cfg_if! {
             /// * `conn` - The database connection to use
             ///
             /// ## Returns
-            /// * `Result<Self, PerseError>` - The Homepage View
-            pub async fn get_homepage(conn: &PgPool) -> Result<Self, PerseError> {
+            /// * `Result<Option<Self>, PerseError>` - The Homepage View, or `None` if no View is the Homepage
+            pub async fn get_homepage(conn: &PgPool) -> Result<Option<Self>, PerseError> {
                 query_as!(
                     Self,
                     "
@@ -29,31 +40,67 @@ cfg_if::cfg_if! {
                     title,
                     content_body,
                     content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
                     description,
                     route,
-                    is_homepage
+                    is_homepage,
+                    deleted_at,
+                    published_at,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                    parent_id,
+                    view_path(parent_id, route) AS \"path!\",
+                    tags
                     FROM views
-                    WHERE visibility = $1 AND is_homepage = TRUE
+                    WHERE visibility = $1
+                    AND deleted_at IS NULL
+                    AND published_at IS NOT NULL
+                    AND (publish_at IS NULL OR publish_at <= CURRENT_TIMESTAMP)
+                    AND (unpublish_at IS NULL OR unpublish_at > CURRENT_TIMESTAMP)
+                    AND (is_homepage = TRUE OR homepage_at <= CURRENT_TIMESTAMP)
+                    -- A View scheduled to become the Homepage takes over as soon as it is due, before the scheduler has run
+                    ORDER BY homepage_at DESC NULLS LAST
+                    LIMIT 1
                     ",
                     // Only retrieve a route that is visible to the public
                     ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes,
                 )
-                .fetch_one(conn)
+                .fetch_optional(conn)
                 .await
                 .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the Homepage View: {err}")))
             }
 
-            /// # Retrieve a View from the Database by Route, if one exists
+            /// # Retrieve a View from the Database by its full path, if one exists
+            ///
+            /// The path is resolved by walking down the tree of Views, one route segment at a time.
             ///
             /// ## Fields
             /// * `conn` - The database connection to use
+            /// * `path` - The requested path, such as `docs/getting-started/install`
             ///
             /// ## Returns
-            /// * `Result<Self, PerseError>` - The View
-            pub async fn get_by_route(conn: &PgPool, route: &str) -> Result<Self, PerseError> {
+            /// * `Result<Option<Self>, PerseError>` - The View, or `None` if no public View has the path
+            pub async fn get_by_route(conn: &PgPool, path: &str) -> Result<Option<Self>, PerseError> {
+                let segments: Vec<String> = path
+                    .split('/')
+                    .filter(|segment| !segment.is_empty())
+                    .map(str::to_string)
+                    .collect();
+
                 query_as!(
                     Self,
                     "
+                    WITH RECURSIVE resolved AS (
+                        SELECT id, 1 AS depth
+                        FROM views
+                        WHERE parent_id IS NULL AND route = ($2::TEXT[])[1] AND deleted_at IS NULL
+                        UNION ALL
+                        SELECT views.id, resolved.depth + 1
+                        FROM views
+                        JOIN resolved ON views.parent_id = resolved.id
+                        WHERE views.route = ($2::TEXT[])[resolved.depth + 1] AND views.deleted_at IS NULL
+                    )
                     SELECT 
                     id,
                     created_at,
@@ -62,133 +109,143 @@ cfg_if::cfg_if! {
                     title,
                     content_body,
                     content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
                     description,
                     route,
-                    is_homepage
+                    is_homepage,
+                    deleted_at,
+                    published_at,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                    parent_id,
+                    view_path(parent_id, route) AS \"path!\",
+                    tags
                     FROM views
-                    WHERE visibility = $1 AND route = $2
+                    WHERE id = (SELECT id FROM resolved WHERE depth = CARDINALITY($2::TEXT[]))
+                    AND visibility = $1
+                    AND deleted_at IS NULL
+                    AND published_at IS NOT NULL
+                    AND (publish_at IS NULL OR publish_at <= CURRENT_TIMESTAMP)
+                    AND (unpublish_at IS NULL OR unpublish_at > CURRENT_TIMESTAMP)
                     ",
                     // Only retrieve a route that is visible to the public
                     ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes,
-                    route,
+                    &segments,
                 )
-                .fetch_one(conn)
+                .fetch_optional(conn)
                 .await
                 .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve View by Route: {err}")))
             }
 
-            // /// # Retrieve a collection of all active routes from the Database
-            // ///
-            // /// ## Returns
-            // /// * `Result<Vec<String>, PerseError>` - A collection of all active routes
-            // pub async fn _get_active_routes() -> Result<Vec<String>, PerseError> {
-            //     Ok(
-            //         query_scalar::<_, String>(
-            //             r#"
-            //             SELECT
-            //             route
-            //             FROM views
-            //             WHERE visibility = $1
-            //             -- Order by is_homepage DESC NULLS LAST to ensure that the homepage is always first
-            //             ORDER BY is_homepage DESC NULLS LAST
-            //             "#
-            //         )
-            //         // Only retrieve routes that are visible to the public
-            //         .bind(ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes)
-            //         // Get a database connection
-            //         .fetch_all(Database::get()?)
-            //         .await?
-            //     )
-            // }
-
-            /// # Update the homepage view
+            /// # Count the Views that are listed in the sitemap
             ///
             /// ## Fields
-            /// * `transaction` - The database transaction in use
-            /// * `view_id` - The ID of the View to update
+            /// * `conn` - The database connection to use
             ///
             /// ## Returns
-            /// * `Result<(), PerseError>` - Returns void
-            pub async fn update_homepage(
-                transaction: &mut Transaction<'_, Postgres>,
-                view_id: &uuid::Uuid
-            ) -> Result<(), PerseError> {
-                // Update the homepage view
-                query!("UPDATE views SET is_homepage = FALSE WHERE is_homepage = TRUE")
-                    .execute(&mut **transaction)
-                    .await
-                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to remove the old homepage view: {err}")))?;
-
-                // Set the new homepage view
-                query!("UPDATE views SET is_homepage = TRUE WHERE id = $1", view_id)
-                    .execute(&mut **transaction)
-                    .await
-                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to update the new homepage view: {err}")))?;
-
-                Ok(())
+            /// * `Result<i64, PerseError>` - The number of public, published Views
+            pub async fn count_public(conn: &PgPool) -> Result<i64, PerseError> {
+                query!(
+                    "
+                    SELECT COUNT(id) AS \"count!\"
+                    FROM views
+                    WHERE visibility = $1
+                    AND deleted_at IS NULL
+                    AND published_at IS NOT NULL
+                    AND (publish_at IS NULL OR publish_at <= CURRENT_TIMESTAMP)
+                    AND (unpublish_at IS NULL OR unpublish_at > CURRENT_TIMESTAMP)
+                    ",
+                    // Only count Views that are visible to the public
+                    ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes,
+                )
+                .map(|row| row.count)
+                .fetch_one(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to count the public Views: {err}")))
             }
-        }
 
-        impl PerseDatabaseModels for View {
-            type CreateRequest = View;
+            /// # Stream a page of the Views that are listed in the sitemap
+            ///
+            /// Unlisted and hidden Views are left out, and the Homepage is always first.
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `offset` - The number of Views to skip
+            /// * `limit` - The maximum number of Views to stream
+            ///
+            /// ## Returns
+            /// * `impl Stream<Item = Result<ViewSitemapEntry, PerseError>>` - The public, published Views
+            pub fn get_public(
+                conn: &'static PgPool,
+                offset: i64,
+                limit: i64,
+            ) -> impl Stream<Item = Result<ViewSitemapEntry, PerseError>> + Send + 'static {
+                query_as!(
+                    ViewSitemapEntry,
+                    "
+                    SELECT
+                    view_path(parent_id, route) AS \"path!\",
+                    is_homepage,
+                    COALESCE(updated_at, created_at) AS \"updated_at!\"
+                    FROM views
+                    WHERE visibility = $1
+                    AND deleted_at IS NULL
+                    AND published_at IS NOT NULL
+                    AND (publish_at IS NULL OR publish_at <= CURRENT_TIMESTAMP)
+                    AND (unpublish_at IS NULL OR unpublish_at > CURRENT_TIMESTAMP)
+                    ORDER BY is_homepage DESC, created_at, id
+                    OFFSET $2
+                    LIMIT $3
+                    ",
+                    // Only retrieve Views that are visible to the public
+                    ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes,
+                    offset,
+                    limit,
+                )
+                .fetch(conn)
+                .map(|entry| entry
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the public Views: {err}"))))
+            }
 
-            /// # Create and return a new `View` record
+            /// # Retrieve the paths of every unlisted View
             ///
             /// ## Fields
-            /// * `transaction` - The database transaction in use
-            /// * `view` - The `NewView` to insert into the Database
+            /// * `conn` - The database connection to use
             ///
             /// ## Returns
-            /// * `Result<Self, PerseError>` - The newly created View
-            async fn create(transaction: &mut Transaction<'_, Postgres>, view: &Self::CreateRequest) -> Result<Self, PerseError> {
-                // Create and retrieve entity
-                let view = query_as!(
-                    Self,
+            /// * `Result<Vec<String>, PerseError>` - The full path of each unlisted View
+            pub async fn get_unlisted_paths(conn: &PgPool) -> Result<Vec<String>, PerseError> {
+                query!(
                     "
-                    INSERT INTO views (visibility, title, content_body, content_head, description, route, is_homepage)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7)
-                    RETURNING id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, description, route, is_homepage
+                    SELECT view_path(parent_id, route) AS \"path!\"
+                    FROM views
+                    WHERE visibility = $1 AND deleted_at IS NULL
+                    ORDER BY created_at
                     ",
-                    view.visibility.clone() as ViewVisibilityTypes,
-                    view.title,
-                    view.content_body,
-                    view.content_head,
-                    view.description,
-                    view.route,
-                    view.is_homepage
+                    ViewVisibilityTypes::VisibilityUnlisted as ViewVisibilityTypes,
                 )
-                .fetch_one(&mut **transaction)
+                .map(|row| row.path)
+                .fetch_all(conn)
                 .await
-                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to create View: {err}")))?;
-
-                // Update this View if it's been declared as the new home page
-                if view.is_homepage {
-                    if let Some(view_id) = view.id {
-                        View::update_homepage(transaction, &view_id).await?;
-                    }
-                }
-
-                Ok(view)
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the unlisted Views: {err}")))
             }
 
-            /// # Retrieve a `View` record from the database by ID
+            /// # Retrieve the newest Views for a feed
             ///
             /// ## Fields
             /// * `conn` - The database connection to use
-            /// * `id` - The ID of the View to retrieve
+            /// * `parent_id` - Only include Views nested directly under this View
+            /// * `tag` - Only include Views with this tag
+            /// * `limit` - The maximum number of Views to retrieve
             ///
             /// ## Returns
-            /// * `Result<Self, PerseError>` - The View record
-            async fn get_by_id(conn: &PgPool, id: &str) -> Result<Self, PerseError> {
-                // Parse the ID into a UUID
-                let id: Uuid = Uuid::parse_str(id)
-                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to parse the ID as a UUID: {err}")))?;
-
-                // Retrieve the View record from the database
-                let query = query_as!(
+            /// * `Result<Vec<Self>, PerseError>` - The public, published Views, newest first
+            pub async fn get_feed(conn: &PgPool, parent_id: Option<&Uuid>, tag: Option<&str>, limit: i64) -> Result<Vec<Self>, PerseError> {
+                query_as!(
                     Self,
                     "
-                    SELECT 
+                    SELECT
                     id,
                     created_at,
                     updated_at,
@@ -196,30 +253,49 @@ cfg_if::cfg_if! {
                     title,
                     content_body,
                     content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
                     description,
                     route,
-                    is_homepage
+                    is_homepage,
+                    deleted_at,
+                    published_at,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                    parent_id,
+                    view_path(parent_id, route) AS \"path!\",
+                    tags
                     FROM views
-                    WHERE id = $1
+                    WHERE visibility = $1
+                    AND deleted_at IS NULL
+                    AND published_at IS NOT NULL
+                    AND (publish_at IS NULL OR publish_at <= CURRENT_TIMESTAMP)
+                    AND (unpublish_at IS NULL OR unpublish_at > CURRENT_TIMESTAMP)
+                    AND ($2::UUID IS NULL OR parent_id = $2)
+                    AND ($3::TEXT IS NULL OR tags @> ARRAY[$3]::TEXT[])
+                    ORDER BY created_at DESC, id
+                    LIMIT $4
                     ",
-                    id,
+                    // Only retrieve Views that are visible to the public
+                    ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes,
+                    parent_id,
+                    tag,
+                    limit,
                 )
-                .fetch_one(conn)
+                .fetch_all(conn)
                 .await
-                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve View by ID: {err}")))?;
-
-                Ok(query)
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the Views for the feed: {err}")))
             }
 
-            /// # Retrieve a collection of all Views from the Database
+            /// # Retrieve a collection of all trashed Views from the Database
             ///
             /// ## Fields
             /// * `conn` - The database connection to use
             ///
             /// ## Returns
-            /// * `Result<Vec<View>, PerseError>` - A collection of all Views
-            async fn get_all(conn: &PgPool) -> Result<Vec<View>, PerseError> {
-                let query =query_as!(
+            /// * `Result<Vec<View>, PerseError>` - A collection of all trashed Views
+            pub async fn get_trashed(conn: &PgPool) -> Result<Vec<Self>, PerseError> {
+                query_as!(
                     Self,
                     "
                     SELECT
@@ -230,85 +306,1543 @@ cfg_if::cfg_if! {
                     title,
                     content_body,
                     content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
                     description,
                     route,
-                    is_homepage
+                    is_homepage,
+                    deleted_at,
+                    published_at,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                    parent_id,
+                    view_path(parent_id, route) AS \"path!\",
+                    tags
                     FROM views
-                    ORDER BY is_homepage DESC NULLS LAST
+                    WHERE deleted_at IS NOT NULL
+                    ORDER BY deleted_at DESC
                     ",
                 )
                 .fetch_all(conn)
                 .await
-                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve all Views: {err}")))?;
-
-                Ok(query)
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the trashed Views: {err}")))
             }
-        }
 
-        impl NewView {
-            /// # Determine the URL path for a new View
+            /// # Restore a trashed View
             ///
             /// ## Fields
-            /// * `data` - The `NewView` data to generate the URL path from
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the View to restore
             ///
             /// ## Returns
-            /// * `Result<String, PerseError>` - The URL path for the new View
-            pub async fn determine_url_path(
-                transaction: &mut Transaction<'_, Postgres>,
-                data: &Self
-            ) -> Result<String, PerseError> {
-                let mut route = data.route.to_string();
+            /// * `Result<Self, PerseError>` - The restored View
+            pub async fn restore(transaction: &mut Transaction<'_, Postgres>, id: &str) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
 
-                // Check if the Route already exists using sqlx. And if it does, append a number to the end of the route until it doesn't exist
-                let mut i = 0;
-                loop {
-                    // Check if the Route already exists in the database, using sqlx
-                    let count: i64 = query!("SELECT COUNT(route) FROM views WHERE route = $1", route)
-                        .map(|row| row.count)
-                        .fetch_optional(&mut **transaction)
-                        .await
-                        .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to determine if the route already exists: {err}")))?
-                        .unwrap_or(Some(0))
-                        .unwrap_or(0);
-
-                    // If the route already exists, increment the counter and route
-                    if count != 0 {
-                        // If we've attempted to generate a unique URL path 10 times, stop
-                        i += 1;
-                        if i >= 10 {
-                            Err(PerseError::new(ErrorTypes::InternalError, "Failed to determine a unique route for the requested URL".to_string()))?;
-                        }
+                // Ensure the route hasn't been taken by another View under the same parent while this one was in the trash
+                let conflicts: i64 = query!(
+                    "
+                    SELECT COUNT(active.id)
+                    FROM views trashed
+                    JOIN views active ON active.route = trashed.route
+                        AND active.parent_id IS NOT DISTINCT FROM trashed.parent_id
+                        AND active.deleted_at IS NULL
+                    WHERE trashed.id = $1
+                    ",
+                    id,
+                )
+                .map(|row| row.count)
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to determine if the route is in use: {err}")))?
+                .unwrap_or(0);
 
-                        // Append a number to the end of the route
-                        route = format!("{route}-");
-                        continue;
-                    }
+                if conflicts != 0 {
+                    Err(PerseError::new(ErrorTypes::Conflict, "The View could not be restored, as its route is now used by another View."))?;
+                }
+
+                // Ensure the parent View isn't still in the trash
+                let trashed_parents: i64 = query!(
+                    "
+                    SELECT COUNT(parent.id)
+                    FROM views trashed
+                    JOIN views parent ON parent.id = trashed.parent_id AND parent.deleted_at IS NOT NULL
+                    WHERE trashed.id = $1
+                    ",
+                    id,
+                )
+                .map(|row| row.count)
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the parent View: {err}")))?
+                .unwrap_or(0);
 
-                    break;
+                if trashed_parents != 0 {
+                    Err(PerseError::new(ErrorTypes::Conflict, "The View could not be restored, as its parent View is in the trash."))?;
                 }
 
-                Ok(route)
+                // Restore and retrieve entity
+                query_as!(
+                    Self,
+                    "
+                    UPDATE views
+                    SET deleted_at = NULL
+                    WHERE id = $1 AND deleted_at IS NOT NULL
+                    RETURNING id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, content_format AS \"content_format: ViewContentFormatTypes\", description, route, is_homepage, deleted_at, published_at, publish_at, unpublish_at, homepage_at, parent_id, view_path(parent_id, route) AS \"path!\", tags
+                    ",
+                    id,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to restore View: {err}")))
             }
-        }
 
-        impl PerseApiRequests for NewView {
-            /// # Validate the incoming `NewView` API request
+            /// # Permanently delete a trashed View
             ///
             /// ## Fields
-            /// * `self` - The `NewView` to validate
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the View to purge
             ///
             /// ## Returns
-            /// * `Result<bool, PerseError>` - Whether the `NewView` is valid
-            fn is_valid(&self) -> Result<(), PerseError> {
-                use validator::Validate;
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn purge(transaction: &mut Transaction<'_, Postgres>, id: &str) -> Result<(), PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
 
-                // Request validation
-                self.validate()?;
+                // Only Views in the trash can be purged
+                let result = query!("DELETE FROM views WHERE id = $1 AND deleted_at IS NOT NULL", id)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| match err.as_database_error().map(|err| err.kind()) {
+                        // Views which are still nested under this View keep it from being purged
+                        Some(sqlx::error::ErrorKind::ForeignKeyViolation) => PerseError::new(
+                            ErrorTypes::Conflict,
+                            "The View could not be purged, as other Views are nested under it.",
+                        ),
+                        _ => PerseError::new(ErrorTypes::InternalError, format!("Failed to purge View: {err}")),
+                    })?;
 
-                // TODO: Custom Validation
+                if result.rows_affected() == 0 {
+                    Err(PerseError::new(ErrorTypes::Conflict, "The View could not be purged, as it is not in the trash."))?;
+                }
 
                 Ok(())
             }
-        }
+
+            /// # Retrieve the Revisions of a View, newest first
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `id` - The ID of the View
+            ///
+            /// ## Returns
+            /// * `Result<Vec<ViewRevision>, PerseError>` - A collection of the View's Revisions
+            pub async fn revisions(conn: &PgPool, id: &str) -> Result<Vec<ViewRevision>, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                query_as!(
+                    ViewRevision,
+                    "
+                    SELECT
+                    id,
+                    view_id,
+                    created_at,
+                    author,
+                    visibility AS \"visibility: ViewVisibilityTypes\",
+                    title,
+                    content_body,
+                    content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
+                    description,
+                    route
+                    FROM view_revisions
+                    WHERE view_id = $1
+                    ORDER BY created_at DESC
+                    ",
+                    id,
+                )
+                .fetch_all(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the View's Revisions: {err}")))
+            }
+
+            /// # Restore a View to the content of one of its Revisions
+            ///
+            /// The View is updated as normal, so restoring records a new Revision.
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `revision_id` - The ID of the Revision to restore
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The restored View
+            pub async fn restore_revision(transaction: &mut Transaction<'_, Postgres>, revision_id: &str) -> Result<Self, PerseError> {
+                // Retrieve the Revision
+                let revision: ViewRevision = ViewRevision::get_by_id(&mut **transaction, revision_id).await?;
+
+                // Keep the View's Homepage status, parent, and tags, as they aren't part of a Revision
+                let (is_homepage, parent_id, tags) = query!("SELECT is_homepage, parent_id, tags FROM views WHERE id = $1", revision.view_id)
+                    .map(|row| (row.is_homepage, row.parent_id, row.tags))
+                    .fetch_one(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the View for the Revision: {err}")))?;
+
+                // Ensure the Revision's route isn't in use by another View
+                let route: String = View::determine_unique_route(transaction, &revision.route, parent_id.as_ref(), Some(&revision.view_id)).await?;
+
+                let view = View {
+                    visibility: revision.visibility,
+                    title: revision.title,
+                    content_body: revision.content_body,
+                    content_head: revision.content_head,
+                    content_format: revision.content_format,
+                    description: revision.description,
+                    route,
+                    is_homepage,
+                    parent_id,
+                    tags,
+                    ..View::default()
+                };
+
+                View::update(transaction, &revision.view_id.to_string(), &view).await
+            }
+
+            /// # Retrieve a `View` record from the database by ID, locking it until the transaction ends
+            ///
+            /// Changes to the View are checked against this record, so it can't change before they are made.
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the View to retrieve
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The View record
+            pub async fn get_by_id_for_update(transaction: &mut Transaction<'_, Postgres>, id: &str) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                // Retrieve and lock the View record
+                let query = query_as!(
+                    Self,
+                    "
+                    SELECT 
+                    id,
+                    created_at,
+                    updated_at,
+                    visibility AS \"visibility: ViewVisibilityTypes\",
+                    title,
+                    content_body,
+                    content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
+                    description,
+                    route,
+                    is_homepage,
+                    deleted_at,
+                    published_at,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                    parent_id,
+                    view_path(parent_id, route) AS \"path!\",
+                    tags
+                    FROM views
+                    WHERE id = $1 AND deleted_at IS NULL
+                    FOR UPDATE
+                    ",
+                    id,
+                )
+                .fetch_optional(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve View by ID: {err}")))?;
+
+                query.ok_or_else(|| PerseError::new(ErrorTypes::NotFound, "The View doesn't exist."))
+            }
+
+            /// # Publish a View, promoting its pending Draft if it has one
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the View to publish
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The published View
+            pub async fn publish(transaction: &mut Transaction<'_, Postgres>, id: &str) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                match ViewDraft::get_by_view_id(&mut **transaction, &id).await? {
+                    // Promote the Draft, and discard it once it has been applied
+                    Some(draft) => {
+                        let route: String = View::determine_unique_route(transaction, &draft.route, draft.parent_id.as_ref(), Some(&id)).await?;
+                        let view = View { route, ..draft.apply(View::default()) };
+
+                        View::update(transaction, &id.to_string(), &view).await?;
+                        ViewDraft::discard(transaction, &id).await?;
+                    }
+                    // Without a Draft, only a View that has never been published can be published
+                    None => {
+                        let published_at = query!("SELECT published_at FROM views WHERE id = $1 AND deleted_at IS NULL", id)
+                            .map(|row| row.published_at)
+                            .fetch_one(&mut **transaction)
+                            .await
+                            .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the View to publish: {err}")))?;
+
+                        if published_at.is_some() {
+                            Err(PerseError::new(ErrorTypes::Conflict, "The View has no unpublished changes."))?;
+                        }
+                    }
+                }
+
+                // Mark the View as published
+                let view = query_as!(
+                    Self,
+                    "
+                    UPDATE views
+                    SET published_at = CURRENT_TIMESTAMP
+                    WHERE id = $1 AND deleted_at IS NULL
+                    RETURNING id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, content_format AS \"content_format: ViewContentFormatTypes\", description, route, is_homepage, deleted_at, published_at, publish_at, unpublish_at, homepage_at, parent_id, view_path(parent_id, route) AS \"path!\", tags
+                    ",
+                    id,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to publish View: {err}")))?;
+
+                // A View may have been declared as the Homepage before it was first published
+                if view.is_homepage {
+                    View::update_homepage(transaction, &id).await?;
+                }
+
+                Ok(view)
+            }
+
+            /// # Schedule a View to be published, unpublished, or become the Homepage
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the View to schedule
+            /// * `schedule` - The dates to schedule, where a missing date clears that schedule
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The scheduled View
+            pub async fn schedule(transaction: &mut Transaction<'_, Postgres>, id: &str, schedule: &ViewSchedule) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                // Parse the scheduled dates
+                let publish_at = ViewSchedule::parse_date(&schedule.publish_at)?;
+                let unpublish_at = ViewSchedule::parse_date(&schedule.unpublish_at)?;
+                let homepage_at = ViewSchedule::parse_date(&schedule.homepage_at)?;
+
+                if let (Some(publish_at), Some(unpublish_at)) = (publish_at, unpublish_at) {
+                    if unpublish_at <= publish_at {
+                        Err(PerseError::new(ErrorTypes::Validation, "The View must be unpublished after it is published."))?;
+                    }
+                }
+
+                query_as!(
+                    Self,
+                    "
+                    UPDATE views
+                    SET publish_at = $2, unpublish_at = $3, homepage_at = $4
+                    WHERE id = $1 AND deleted_at IS NULL
+                    RETURNING id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, content_format AS \"content_format: ViewContentFormatTypes\", description, route, is_homepage, deleted_at, published_at, publish_at, unpublish_at, homepage_at, parent_id, view_path(parent_id, route) AS \"path!\", tags
+                    ",
+                    id,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to schedule View: {err}")))
+            }
+
+            /// # Apply every scheduled change that is due
+            ///
+            /// Views due to be published are published and made public, Views due to be unpublished are hidden,
+            /// and the latest View due to become the Homepage replaces it. Each change is recorded as a Transition.
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            ///
+            /// ## Returns
+            /// * `Result<Vec<ViewScheduleTransition>, PerseError>` - The Transitions that were applied
+            pub async fn apply_schedules(transaction: &mut Transaction<'_, Postgres>) -> Result<Vec<ViewScheduleTransition>, PerseError> {
+                let mut transitions: Vec<ViewScheduleTransition> = Vec::new();
+
+                // Publish the Views that are due, promoting their Drafts
+                let due = query!(
+                    "
+                    SELECT id, publish_at AS \"publish_at!\"
+                    FROM views
+                    WHERE publish_at <= CURRENT_TIMESTAMP AND deleted_at IS NULL
+                    FOR UPDATE SKIP LOCKED
+                    "
+                )
+                .fetch_all(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the Views due to be published: {err}")))?;
+
+                for view in due {
+                    if ViewDraft::get_by_view_id(&mut **transaction, &view.id).await?.is_some() {
+                        View::publish(transaction, &view.id.to_string()).await?;
+                    }
+
+                    query!(
+                        "
+                        UPDATE views
+                        SET visibility = $2, published_at = COALESCE(published_at, CURRENT_TIMESTAMP), publish_at = NULL
+                        WHERE id = $1
+                        ",
+                        view.id,
+                        ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes,
+                    )
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to publish the scheduled View: {err}")))?;
+
+                    transitions.push(
+                        ViewScheduleTransition::record(transaction, &view.id, ViewScheduleTransitionTypes::TransitionPublished, view.publish_at).await?,
+                    );
+                }
+
+                // Unpublish the Views that are due, by hiding them
+                let due = query!(
+                    "
+                    UPDATE views
+                    SET visibility = $1, unpublish_at = NULL
+                    FROM (
+                        SELECT id, unpublish_at FROM views
+                        WHERE unpublish_at <= CURRENT_TIMESTAMP AND deleted_at IS NULL
+                        FOR UPDATE SKIP LOCKED
+                    ) due
+                    WHERE views.id = due.id
+                    RETURNING views.id, due.unpublish_at AS \"unpublish_at!\"
+                    ",
+                    ViewVisibilityTypes::VisibilityHidden as ViewVisibilityTypes,
+                )
+                .fetch_all(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to unpublish the scheduled Views: {err}")))?;
+
+                for view in due {
+                    transitions.push(
+                        ViewScheduleTransition::record(transaction, &view.id, ViewScheduleTransitionTypes::TransitionUnpublished, view.unpublish_at).await?,
+                    );
+                }
+
+                // Replace the Homepage with the latest View that is due, and clear every Homepage schedule that has passed
+                let due = query!(
+                    "
+                    UPDATE views
+                    SET homepage_at = NULL
+                    FROM (
+                        SELECT id, homepage_at FROM views
+                        WHERE homepage_at <= CURRENT_TIMESTAMP AND deleted_at IS NULL
+                        FOR UPDATE SKIP LOCKED
+                    ) due
+                    WHERE views.id = due.id
+                    RETURNING views.id, due.homepage_at AS \"homepage_at!\"
+                    "
+                )
+                .fetch_all(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the View due to become the Homepage: {err}")))?;
+
+                if let Some(view) = due.into_iter().max_by_key(|view| view.homepage_at) {
+                    // Only a published View that passes the Homepage rules can replace it
+                    let target = query!(
+                        "
+                        SELECT visibility AS \"visibility: ViewVisibilityTypes\", published_at
+                        FROM views
+                        WHERE id = $1
+                        ",
+                        view.id,
+                    )
+                    .fetch_one(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the View due to become the Homepage: {err}")))?;
+
+                    let skipped: Option<&str> = homepage_error(&target.visibility)
+                        .or_else(|| target.published_at.is_none().then_some("An unpublished View can't be the homepage."));
+
+                    match skipped {
+                        Some(reason) => log!("Skipped the scheduled `{}` transition of View {}: {reason}", ViewScheduleTransitionTypes::TransitionHomepage, view.id),
+                        None => {
+                            View::update_homepage(transaction, &view.id).await?;
+
+                            transitions.push(
+                                ViewScheduleTransition::record(transaction, &view.id, ViewScheduleTransitionTypes::TransitionHomepage, view.homepage_at).await?,
+                            );
+                        }
+                    }
+                }
+
+                Ok(transitions)
+            }
+
+            /// # Continuously apply scheduled changes to Views
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `interval` - The number of seconds between each run
+            pub async fn run_schedules(conn: &PgPool, interval: u64) {
+                let mut timer = tokio::time::interval(std::time::Duration::from_secs(interval));
+
+                loop {
+                    timer.tick().await;
+
+                    let result: Result<Vec<ViewScheduleTransition>, PerseError> = async {
+                        let mut transaction = conn.begin().await?;
+                        let transitions = View::apply_schedules(&mut transaction).await?;
+                        transaction.commit().await?;
+
+                        Ok(transitions)
+                    }
+                    .await;
+
+                    // Errors have already been logged by `PerseError`, so try again on the next run
+                    if let Ok(transitions) = result {
+                        for transition in transitions {
+                            log!("Applied the scheduled `{}` transition to View {}", transition.transition, transition.view_id);
+                        }
+                    }
+                }
+            }
+
+            /// # Create a signed Preview token for a View
+            ///
+            /// The token expires after the configured `preview_lifetime`.
+            ///
+            /// ## Fields
+            /// * `id` - The ID of the View to preview
+            ///
+            /// ## Returns
+            /// * `Result<String, PerseError>` - The Preview token
+            pub fn preview_token(id: &str) -> Result<String, PerseError> {
+                use hmac::Mac;
+
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                let expires_at: i64 = sqlx::types::chrono::Utc::now().timestamp() + Configuration::get().preview_lifetime;
+                let payload = format!("{id}.{expires_at}");
+                let signature = hex::encode(Self::preview_signer(&payload)?.finalize().into_bytes());
+
+                Ok(format!("{payload}.{signature}"))
+            }
+
+            /// # Retrieve a View as it will be once its Draft is published, using a Preview token
+            ///
+            /// The token only previews its View at the View's own path, or at the root URL if the View is the Homepage.
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `token` - The signed Preview token
+            /// * `path` - The requested path, or `None` if the Homepage was requested
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The View, with its Draft applied
+            pub async fn get_preview(conn: &PgPool, token: &str, path: Option<&str>) -> Result<Self, PerseError> {
+                use hmac::Mac;
+
+                let invalid = || PerseError::new(ErrorTypes::Validation, "The preview link is invalid or has expired.");
+
+                // Split the token into its payload and signature
+                let (payload, signature) = token.rsplit_once('.').ok_or_else(invalid)?;
+                let (id, expires_at) = payload.split_once('.').ok_or_else(invalid)?;
+
+                // Verify the signature, and that the token hasn't expired
+                Self::preview_signer(payload)?
+                    .verify_slice(&hex::decode(signature).map_err(|_| invalid())?)
+                    .map_err(|_| invalid())?;
+
+                if expires_at.parse::<i64>().map_err(|_| invalid())? < sqlx::types::chrono::Utc::now().timestamp() {
+                    Err(invalid())?;
+                }
+
+                // Retrieve the View, and ensure it was requested through its own path
+                let view: View = View::get_by_id(conn, id).await?;
+                let id: Uuid = Uuid::parse_str(id).map_err(|_| invalid())?;
+
+                let requested: bool = match path {
+                    Some(path) => view.path == path.trim_matches('/'),
+                    None => view.is_homepage,
+                };
+
+                if !requested {
+                    Err(PerseError::new(ErrorTypes::NotFound, "The previewed View doesn't exist at this path."))?;
+                }
+
+                // Apply the Draft if there is one
+
+                Ok(match ViewDraft::get_by_view_id(conn, &id).await? {
+                    Some(draft) => draft.apply(view),
+                    None => view,
+                })
+            }
+
+            /// # Create the signer for Preview tokens
+            ///
+            /// ## Fields
+            /// * `payload` - The payload to sign
+            ///
+            /// ## Returns
+            /// * `Result<Hmac<Sha256>, PerseError>` - The signer, with the payload applied
+            fn preview_signer(payload: &str) -> Result<hmac::Hmac<sha2::Sha256>, PerseError> {
+                use hmac::Mac;
+
+                let mut signer = hmac::Hmac::<sha2::Sha256>::new_from_slice(Configuration::get().preview_secret.as_bytes())
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to create the preview signer: {err}")))?;
+                signer.update(payload.as_bytes());
+
+                Ok(signer)
+            }
+
+            /// # Prepare the View for rendering
+            ///
+            /// ## Returns
+            /// * `RenderedView` - The View, with the permitted elements of its head content and its rendered body content
+            pub fn render(self) -> RenderedView {
+                let head: Vec<HeadElement> = self
+                    .content_head
+                    .as_deref()
+                    .map(|content_head| HeadElement::parse(content_head, &Configuration::get().content_head_allowed_tags))
+                    .unwrap_or_default();
+
+                // Reuse the previous render unless the View has been updated since
+                let body: Option<RenderedBody> = self
+                    .content_body
+                    .as_deref()
+                    .map(|content_body| match (&self.id, &self.updated_at.or(self.created_at)) {
+                        (Some(id), Some(updated_at)) => RenderedBody::render_cached(id, updated_at, content_body, self.content_format),
+                        _ => RenderedBody::render(content_body, self.content_format),
+                    });
+
+                RenderedView { view: self, head, body }
+            }
+
+            // /// # Retrieve a collection of all active routes from the Database
+            // ///
+            // /// ## Returns
+            // /// * `Result<Vec<String>, PerseError>` - A collection of all active routes
+            // pub async fn _get_active_routes() -> Result<Vec<String>, PerseError> {
+            //     Ok(
+            //         query_scalar::<_, String>(
+            //             r#"
+            //             SELECT
+            //             route
+            //             FROM views
+            //             WHERE visibility = $1
+            //             -- Order by is_homepage DESC NULLS LAST to ensure that the homepage is always first
+            //             ORDER BY is_homepage DESC NULLS LAST
+            //             "#
+            //         )
+            //         // Only retrieve routes that are visible to the public
+            //         .bind(ViewVisibilityTypes::VisibilityPublic as ViewVisibilityTypes)
+            //         // Get a database connection
+            //         .fetch_all(Database::get()?)
+            //         .await?
+            //     )
+            // }
+
+            /// # Update the homepage view
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `view_id` - The ID of the View to update
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn update_homepage(
+                transaction: &mut Transaction<'_, Postgres>,
+                view_id: &uuid::Uuid
+            ) -> Result<(), PerseError> {
+                // Update the homepage view
+                query!("UPDATE views SET is_homepage = FALSE WHERE is_homepage = TRUE")
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to remove the old homepage view: {err}")))?;
+
+                // Set the new homepage view
+                query!("UPDATE views SET is_homepage = TRUE WHERE id = $1 AND deleted_at IS NULL", view_id)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to update the new homepage view: {err}")))?;
+
+                Ok(())
+            }
+        }
+
+        impl PerseDatabaseModels for View {
+            type CreateRequest = View;
+            type UpdateRequest = View;
+
+            /// # Create and return a new `View` record
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `view` - The `NewView` to insert into the Database
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The newly created View
+            async fn create(transaction: &mut Transaction<'_, Postgres>, view: &Self::CreateRequest) -> Result<Self, PerseError> {
+                // Ensure the parent View can hold this View
+                View::validate_parent(transaction, view.parent_id.as_ref(), None).await?;
+
+                // Create and retrieve entity
+                let view = query_as!(
+                    Self,
+                    "
+                    INSERT INTO views (visibility, title, content_body, content_head, description, route, is_homepage, published_at, content_format, parent_id, tags)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                    RETURNING id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, content_format AS \"content_format: ViewContentFormatTypes\", description, route, is_homepage, deleted_at, published_at, publish_at, unpublish_at, homepage_at, parent_id, view_path(parent_id, route) AS \"path!\", tags
+                    ",
+                    view.visibility.clone() as ViewVisibilityTypes,
+                    view.title,
+                    view.content_body,
+                    view.content_head,
+                    view.description,
+                    view.route,
+                    view.is_homepage,
+                    view.published_at,
+                    view.content_format as ViewContentFormatTypes,
+                    view.parent_id,
+                    &view.tags,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to create View: {err}")))?;
+
+                // Update this View if it's been declared as the new home page
+                if view.is_homepage {
+                    if let Some(view_id) = view.id {
+                        View::update_homepage(transaction, &view_id).await?;
+                    }
+                }
+
+                Ok(view)
+            }
+
+            /// # Update and return an existing `View` record
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the View to update
+            /// * `view` - The updated `View` values
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The updated View
+            async fn update(transaction: &mut Transaction<'_, Postgres>, id: &str, view: &Self::UpdateRequest) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                // Ensure the parent View can hold this View, without nesting it under itself
+                View::validate_parent(transaction, view.parent_id.as_ref(), Some(&id)).await?;
+
+                // Keep the paths of this View and its nested Views, so they can be redirected if they change
+                let previous_paths: Vec<(Uuid, String)> = Redirect::view_paths(transaction, &id).await?;
+
+                // Update and retrieve entity
+                let view = query_as!(
+                    Self,
+                    "
+                    UPDATE views
+                    SET visibility = $2, title = $3, content_body = $4, content_head = $5, description = $6, route = $7, is_homepage = $8, content_format = $9, parent_id = $10, tags = $11
+                    WHERE id = $1 AND deleted_at IS NULL
+                    RETURNING id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, content_format AS \"content_format: ViewContentFormatTypes\", description, route, is_homepage, deleted_at, published_at, publish_at, unpublish_at, homepage_at, parent_id, view_path(parent_id, route) AS \"path!\", tags
+                    ",
+                    id,
+                    view.visibility.clone() as ViewVisibilityTypes,
+                    view.title,
+                    view.content_body,
+                    view.content_head,
+                    view.description,
+                    view.route,
+                    view.is_homepage,
+                    view.content_format as ViewContentFormatTypes,
+                    view.parent_id,
+                    &view.tags,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to update View: {err}")))?;
+
+                // Redirect the previous paths of any moved Views
+                Redirect::record_moves(transaction, &previous_paths).await?;
+
+                // Update this View if it's been declared as the new home page
+                if view.is_homepage {
+                    View::update_homepage(transaction, &id).await?;
+                }
+
+                Ok(view)
+            }
+
+            /// # Move a `View` record to the trash
+            ///
+            /// The View is soft-deleted, and can be restored from the trash until it is purged.
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `id` - The ID of the View to delete
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The deleted View
+            async fn delete(transaction: &mut Transaction<'_, Postgres>, id: &str) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                // Views with nested Views must have them moved or deleted first
+                let children: i64 = query!("SELECT COUNT(id) FROM views WHERE parent_id = $1 AND deleted_at IS NULL", id)
+                    .map(|row| row.count)
+                    .fetch_one(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the View's nested Views: {err}")))?
+                    .unwrap_or(0);
+
+                if children != 0 {
+                    Err(PerseError::new(ErrorTypes::Conflict, "The View could not be deleted, as other Views are nested under it."))?;
+                }
+
+                // Delete and retrieve entity, leaving the Homepage in place
+                let view = query_as!(
+                    Self,
+                    "
+                    UPDATE views
+                    SET deleted_at = CURRENT_TIMESTAMP
+                    WHERE id = $1 AND deleted_at IS NULL AND is_homepage = FALSE
+                    RETURNING id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, content_format AS \"content_format: ViewContentFormatTypes\", description, route, is_homepage, deleted_at, published_at, publish_at, unpublish_at, homepage_at, parent_id, view_path(parent_id, route) AS \"path!\", tags
+                    ",
+                    id,
+                )
+                .fetch_optional(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to delete View: {err}")))?;
+
+                // The View either doesn't exist, or is the Homepage and needs replacing before it can be deleted
+                view.ok_or_else(|| PerseError::new(
+                    ErrorTypes::Conflict,
+                    "The View could not be deleted. It may not exist, or it is the Homepage and another View must be made the Homepage first.",
+                ))
+            }
+
+            /// # Retrieve a `View` record from the database by ID
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `id` - The ID of the View to retrieve
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The View record
+            async fn get_by_id(conn: &PgPool, id: &str) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                // Retrieve the View record from the database
+                let query = query_as!(
+                    Self,
+                    "
+                    SELECT 
+                    id,
+                    created_at,
+                    updated_at,
+                    visibility AS \"visibility: ViewVisibilityTypes\",
+                    title,
+                    content_body,
+                    content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
+                    description,
+                    route,
+                    is_homepage,
+                    deleted_at,
+                    published_at,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                    parent_id,
+                    view_path(parent_id, route) AS \"path!\",
+                    tags
+                    FROM views
+                    WHERE id = $1 AND deleted_at IS NULL
+                    ",
+                    id,
+                )
+                .fetch_optional(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve View by ID: {err}")))?;
+
+                query.ok_or_else(|| PerseError::new(ErrorTypes::NotFound, "The View doesn't exist."))
+            }
+
+            /// # Retrieve a collection of all Views from the Database
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            ///
+            /// ## Returns
+            /// * `Result<Vec<View>, PerseError>` - A collection of all Views
+            async fn get_all(conn: &PgPool) -> Result<Vec<View>, PerseError> {
+                let query =query_as!(
+                    Self,
+                    "
+                    SELECT
+                    id,
+                    created_at,
+                    updated_at,
+                    visibility AS \"visibility: ViewVisibilityTypes\",
+                    title,
+                    content_body,
+                    content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
+                    description,
+                    route,
+                    is_homepage,
+                    deleted_at,
+                    published_at,
+                    publish_at,
+                    unpublish_at,
+                    homepage_at,
+                    parent_id,
+                    view_path(parent_id, route) AS \"path!\",
+                    tags
+                    FROM views
+                    WHERE deleted_at IS NULL
+                    ORDER BY is_homepage DESC NULLS LAST
+                    ",
+                )
+                .fetch_all(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve all Views: {err}")))?;
+
+                Ok(query)
+            }
+        }
+
+        impl View {
+            /// # Ensure a View's content is within the configured size limits
+            ///
+            /// ## Fields
+            /// * `fields` - The messages for each invalid field, to add any size errors to
+            /// * `content_body` - The body content of the View
+            /// * `content_head` - The head content of the View
+            fn validate_content_size(fields: &mut FieldErrors, content_body: Option<&str>, content_head: Option<&str>) {
+                if let Some(content_body) = content_body {
+                    let max_size: usize = Configuration::get().content_body_max_size;
+
+                    if content_body.len() > max_size {
+                        fields.add("content_body", format!("The body content can't be larger than {max_size} bytes."));
+                    }
+                }
+
+                if let Some(content_head) = content_head {
+                    let max_size: usize = Configuration::get().content_head_max_size;
+
+                    if content_head.len() > max_size {
+                        fields.add("content_head", format!("The head content can't be larger than {max_size} bytes."));
+                    }
+                }
+            }
+
+            /// # Ensure a View can be nested under the requested parent View
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `parent_id` - The ID of the requested parent View, if there is one
+            /// * `id` - The ID of the View being nested, if it already exists
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void, or a validation error if the parent is missing or would create a cycle
+            async fn validate_parent(
+                transaction: &mut Transaction<'_, Postgres>,
+                parent_id: Option<&Uuid>,
+                id: Option<&Uuid>,
+            ) -> Result<(), PerseError> {
+                let Some(parent_id) = parent_id else {
+                    return Ok(());
+                };
+
+                // Walk up from the parent View, collecting each of its ancestors
+                let ancestors: Vec<Uuid> = query!(
+                    "
+                    WITH RECURSIVE ancestors AS (
+                        SELECT id, parent_id, 1 AS depth FROM views WHERE id = $1 AND deleted_at IS NULL
+                        UNION ALL
+                        SELECT views.id, views.parent_id, ancestors.depth + 1
+                        FROM views
+                        JOIN ancestors ON views.id = ancestors.parent_id
+                        WHERE ancestors.depth < 64
+                    )
+                    SELECT id AS \"id!\" FROM ancestors
+                    ",
+                    parent_id,
+                )
+                    .map(|row| row.id)
+                    .fetch_all(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the parent View: {err}")))?;
+
+                if ancestors.is_empty() {
+                    Err(PerseError::new(ErrorTypes::Validation, "The parent View does not exist."))?;
+                }
+
+                // A View can't be nested under itself, or under any of its own nested Views
+                if id.is_some_and(|id| ancestors.contains(id)) {
+                    Err(PerseError::new(ErrorTypes::Validation, "A View can't be nested under itself or one of its nested Views."))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Determine a unique route for a View
+            ///
+            /// Routes in use by a sibling View are given the lowest free numeric suffix, such as `about-2` and then `about-3`.
+            /// The route is shortened before the suffix is added, so it still fits the database. Every lookup uses the index of
+            /// sibling routes.
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `route` - The requested route
+            /// * `parent_id` - The ID of the parent View, as routes only need to be unique amongst siblings
+            /// * `exclude_id` - The ID of a View to ignore when checking for duplicates, such as the View being updated
+            ///
+            /// ## Returns
+            /// * `Result<String, PerseError>` - A route that is not in use by any sibling View
+            async fn determine_unique_route(
+                transaction: &mut Transaction<'_, Postgres>,
+                route: &str,
+                parent_id: Option<&Uuid>,
+                exclude_id: Option<&Uuid>,
+            ) -> Result<String, PerseError> {
+                const MAX_ROUTE_LENGTH: usize = 255;
+                // Room for a hyphen and the digits of any suffix, past which the route is shortened
+                const MAX_BASE_LENGTH: usize = MAX_ROUTE_LENGTH - 21;
+
+                // Match the suffixed routes, or the shortened routes of a long route, without wildcards in the route itself
+                let base: String = route.chars().take(MAX_BASE_LENGTH).collect();
+                let base: String = base.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+                let pattern: String = match route.chars().count() > MAX_BASE_LENGTH {
+                    true => format!("{base}%"),
+                    false => format!("{base}-%"),
+                };
+
+                // Keep the route if it's free, or else find the lowest free numeric suffix, shortening the route to fit it
+                query_scalar!(
+                    "
+                    SELECT COALESCE(
+                        (
+                            SELECT $1::TEXT
+                            WHERE NOT EXISTS (
+                                SELECT 1 FROM views
+                                WHERE COALESCE(parent_id, '00000000-0000-0000-0000-000000000000'::UUID) = COALESCE($2, '00000000-0000-0000-0000-000000000000'::UUID)
+                                AND deleted_at IS NULL
+                                AND ($3::UUID IS NULL OR id <> $3)
+                                AND route = $1
+                            )
+                        ),
+                        (
+                            SELECT candidate
+                            FROM generate_series(
+                                2,
+                                (
+                                    SELECT COUNT(*) + 2 FROM views
+                                    WHERE COALESCE(parent_id, '00000000-0000-0000-0000-000000000000'::UUID) = COALESCE($2, '00000000-0000-0000-0000-000000000000'::UUID)
+                                    AND deleted_at IS NULL
+                                    AND ($3::UUID IS NULL OR id <> $3)
+                                    AND route LIKE $4
+                                )
+                            ) AS suffix,
+                            LATERAL (SELECT RTRIM(LEFT($1, $5 - LENGTH('-' || suffix)), '-') || '-' || suffix AS candidate) AS candidates
+                            WHERE NOT EXISTS (
+                                SELECT 1 FROM views
+                                WHERE COALESCE(parent_id, '00000000-0000-0000-0000-000000000000'::UUID) = COALESCE($2, '00000000-0000-0000-0000-000000000000'::UUID)
+                                AND deleted_at IS NULL
+                                AND ($3::UUID IS NULL OR id <> $3)
+                                AND route = candidate
+                            )
+                            ORDER BY suffix
+                            LIMIT 1
+                        )
+                    ) AS \"route!\"
+                    ",
+                    route,
+                    parent_id,
+                    exclude_id,
+                    pattern,
+                    MAX_ROUTE_LENGTH as i32,
+                )
+                    .fetch_one(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to determine if the route already exists: {err}")))
+            }
+        }
+
+        impl NewView {
+            /// # Determine the URL path for a new View
+            ///
+            /// ## Fields
+            /// * `data` - The `NewView` data to generate the URL path from
+            ///
+            /// ## Returns
+            /// * `Result<String, PerseError>` - The URL path for the new View
+            pub async fn determine_url_path(
+                transaction: &mut Transaction<'_, Postgres>,
+                data: &Self
+            ) -> Result<String, PerseError> {
+                View::determine_unique_route(transaction, &data.route, data.parent_id.as_ref(), None).await
+            }
+        }
+
+        impl ViewUpdate {
+            /// # Determine the URL path for an updated View
+            ///
+            /// ## Fields
+            /// * `id` - The ID of the View being updated
+            /// * `data` - The `ViewUpdate` data to generate the URL path from
+            ///
+            /// ## Returns
+            /// * `Result<String, PerseError>` - The URL path for the updated View
+            pub async fn determine_url_path(
+                transaction: &mut Transaction<'_, Postgres>,
+                id: &str,
+                data: &Self
+            ) -> Result<String, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                View::determine_unique_route(transaction, &data.route, data.parent_id.as_ref(), Some(&id)).await
+            }
+        }
+
+        impl PerseApiRequests for NewView {
+            /// # Validate the incoming `NewView` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `NewView` to validate
+            ///
+            /// ## Returns
+            /// * `Result<bool, PerseError>` - Whether the `NewView` is valid
+            fn is_valid(&self) -> Result<(), PerseError> {
+                // Request & Custom validation, shared with the browser
+                let mut fields: FieldErrors = self.field_errors();
+
+                // Content size validation, using the configured limits
+                View::validate_content_size(&mut fields, self.content_body.as_deref(), self.content_head.as_deref());
+
+                if !fields.is_empty() {
+                    Err(PerseError::validation(fields))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Sanitise the incoming `NewView` API request
+            ///
+            /// The title is trimmed, a route is generated from it if none was given, and HTML body content is sanitised using the configured policy, removing scripts and event handlers.
+            ///
+            /// ## Fields
+            /// * `self` - The `NewView` to sanitise
+            fn sanitise(&mut self) {
+                self.title = self.title.trim().to_string();
+                self.route = self.route_or_slug();
+
+                if self.content_format == ViewContentFormatTypes::FormatHtml {
+                    self.content_body = self.content_body.as_deref().map(RenderedBody::sanitise);
+                }
+            }
+        }
+
+        impl PerseApiRequests for ViewUpdate {
+            /// # Validate the incoming `ViewUpdate` API request
+            ///
+            /// ## Fields
+            /// * `self` - The `ViewUpdate` to validate
+            ///
+            /// ## Returns
+            /// * `Result<bool, PerseError>` - Whether the `ViewUpdate` is valid
+            fn is_valid(&self) -> Result<(), PerseError> {
+                // Request & Custom validation, shared with `NewView`
+                let mut fields: FieldErrors = self.field_errors();
+
+                // Content size validation, using the configured limits
+                View::validate_content_size(&mut fields, self.content_body.as_deref(), self.content_head.as_deref());
+
+                if !fields.is_empty() {
+                    Err(PerseError::validation(fields))?;
+                }
+
+                Ok(())
+            }
+
+            /// # Sanitise the incoming `ViewUpdate` API request
+            ///
+            /// The title is trimmed, and HTML body content is sanitised using the configured policy, removing scripts and event handlers.
+            ///
+            /// ## Fields
+            /// * `self` - The `ViewUpdate` to sanitise
+            fn sanitise(&mut self) {
+                self.title = self.title.trim().to_string();
+
+                if self.content_format == ViewContentFormatTypes::FormatHtml {
+                    self.content_body = self.content_body.as_deref().map(RenderedBody::sanitise);
+                }
+            }
+        }
+
+        impl ViewRevision {
+            /// # Retrieve a `ViewRevision` record from the database by ID
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `id` - The ID of the Revision to retrieve
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The Revision record
+            pub async fn get_by_id<'c>(conn: impl sqlx::PgExecutor<'c>, id: &str) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let id: Uuid = Uuid::parse_str(id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                query_as!(
+                    Self,
+                    "
+                    SELECT
+                    id,
+                    view_id,
+                    created_at,
+                    author,
+                    visibility AS \"visibility: ViewVisibilityTypes\",
+                    title,
+                    content_body,
+                    content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
+                    description,
+                    route
+                    FROM view_revisions
+                    WHERE id = $1
+                    ",
+                    id,
+                )
+                .fetch_optional(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve Revision by ID: {err}")))?
+                .ok_or_else(|| PerseError::new(ErrorTypes::NotFound, "The Revision doesn't exist."))
+            }
+
+            /// # Compare two Revisions line by line
+            ///
+            /// ## Fields
+            /// * `self` - The older Revision
+            /// * `other` - The newer Revision
+            ///
+            /// ## Returns
+            /// * `Vec<ViewRevisionDiff>` - The lines of both Revisions, marked as unchanged, added or removed
+            pub fn diff(&self, other: &Self) -> Vec<ViewRevisionDiff> {
+                use similar::{ChangeTag, TextDiff};
+
+                let (old, new) = (self.as_document(), other.as_document());
+
+                TextDiff::from_lines(&old, &new)
+                    .iter_all_changes()
+                    .map(|change| ViewRevisionDiff {
+                        change: match change.tag() {
+                            ChangeTag::Equal => ViewRevisionDiffTypes::Unchanged,
+                            ChangeTag::Insert => ViewRevisionDiffTypes::Added,
+                            ChangeTag::Delete => ViewRevisionDiffTypes::Removed,
+                        },
+                        content: change.to_string_lossy().trim_end_matches('\n').to_string(),
+                    })
+                    .collect()
+            }
+
+            /// # Format the Revision as a plain text document for comparison
+            ///
+            /// ## Returns
+            /// * `String` - The Revision's fields, one after another
+            fn as_document(&self) -> String {
+                format!(
+                    "Title: {}\nRoute: /{}\nVisibility: {}\nFormat: {}\nDescription: {}\n\nHead Content:\n{}\n\nBody Content:\n{}\n",
+                    self.title,
+                    self.route,
+                    self.visibility,
+                    self.content_format,
+                    self.description.as_deref().unwrap_or_default(),
+                    self.content_head.as_deref().unwrap_or_default(),
+                    self.content_body.as_deref().unwrap_or_default(),
+                )
+            }
+        }
+
+        impl ViewDraft {
+            /// # Retrieve the Draft of a View, if it has one
+            ///
+            /// ## Fields
+            /// * `conn` - The database connection to use
+            /// * `view_id` - The ID of the View
+            ///
+            /// ## Returns
+            /// * `Result<Option<Self>, PerseError>` - The View's Draft
+            pub async fn get_by_view_id<'c>(conn: impl sqlx::PgExecutor<'c>, view_id: &Uuid) -> Result<Option<Self>, PerseError> {
+                query_as!(
+                    Self,
+                    "
+                    SELECT
+                    view_id,
+                    created_at,
+                    updated_at,
+                    visibility AS \"visibility: ViewVisibilityTypes\",
+                    title,
+                    content_body,
+                    content_head,
+                    content_format AS \"content_format: ViewContentFormatTypes\",
+                    description,
+                    route,
+                    is_homepage,
+                    parent_id,
+                    tags
+                    FROM view_drafts
+                    WHERE view_id = $1
+                    ",
+                    view_id,
+                )
+                .fetch_optional(conn)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to retrieve the View's Draft: {err}")))
+            }
+
+            /// # Create or replace the Draft of a View
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `view_id` - The ID of the View
+            /// * `draft` - The pending changes to the View
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The saved Draft
+            pub async fn save(transaction: &mut Transaction<'_, Postgres>, view_id: &str, draft: &ViewUpdate) -> Result<Self, PerseError> {
+                // Parse the ID into a UUID
+                let view_id: Uuid = Uuid::parse_str(view_id)
+                    .map_err(|err| PerseError::new(ErrorTypes::BadRequest, format!("Failed to parse the ID as a UUID: {err}")))?;
+
+                query_as!(
+                    Self,
+                    "
+                    INSERT INTO view_drafts (view_id, visibility, title, content_body, content_head, description, route, is_homepage, content_format, parent_id, tags)
+                    SELECT id, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11 FROM views WHERE id = $1 AND deleted_at IS NULL
+                    ON CONFLICT (view_id) DO UPDATE
+                    SET visibility = EXCLUDED.visibility, title = EXCLUDED.title, content_body = EXCLUDED.content_body, content_head = EXCLUDED.content_head,
+                        description = EXCLUDED.description, route = EXCLUDED.route, is_homepage = EXCLUDED.is_homepage, content_format = EXCLUDED.content_format,
+                        parent_id = EXCLUDED.parent_id, tags = EXCLUDED.tags
+                    RETURNING view_id, created_at, updated_at, visibility AS \"visibility: ViewVisibilityTypes\", title, content_body, content_head, content_format AS \"content_format: ViewContentFormatTypes\", description, route, is_homepage, parent_id, tags
+                    ",
+                    view_id,
+                    draft.visibility.clone() as ViewVisibilityTypes,
+                    draft.title,
+                    draft.content_body,
+                    draft.content_head,
+                    draft.description,
+                    draft.route,
+                    draft.is_homepage.is_some(),
+                    draft.content_format as ViewContentFormatTypes,
+                    draft.parent_id,
+                    &draft.tags,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to save the View's Draft: {err}")))
+            }
+
+            /// # Discard the Draft of a View
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `view_id` - The ID of the View
+            ///
+            /// ## Returns
+            /// * `Result<(), PerseError>` - Returns void
+            pub async fn discard(transaction: &mut Transaction<'_, Postgres>, view_id: &Uuid) -> Result<(), PerseError> {
+                query!("DELETE FROM view_drafts WHERE view_id = $1", view_id)
+                    .execute(&mut **transaction)
+                    .await
+                    .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to discard the View's Draft: {err}")))?;
+
+                Ok(())
+            }
+        }
+
+        impl ViewSchedule {
+            /// # Parse a scheduled date
+            ///
+            /// ## Fields
+            /// * `date` - The date, from a `datetime-local` input
+            ///
+            /// ## Returns
+            /// * `Result<Option<NaiveDateTime>, PerseError>` - The parsed date, or `None` if it was empty
+            fn parse_date(date: &Option<String>) -> Result<Option<sqlx::types::chrono::NaiveDateTime>, PerseError> {
+                use sqlx::types::chrono::NaiveDateTime;
+
+                match date.as_deref().map(str::trim) {
+                    None | Some("") => Ok(None),
+                    Some(date) => ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+                        .iter()
+                        .find_map(|format| NaiveDateTime::parse_from_str(date, format).ok())
+                        .map(Some)
+                        .ok_or_else(|| PerseError::new(ErrorTypes::Validation, format!("The scheduled date `{date}` is in an incorrect format."))),
+                }
+            }
+        }
+
+        impl ViewScheduleTransition {
+            /// # Record a Transition that has been applied to a View
+            ///
+            /// ## Fields
+            /// * `transaction` - The database transaction in use
+            /// * `view_id` - The ID of the View
+            /// * `transition` - The change that was applied
+            /// * `scheduled_for` - The date the change was scheduled for
+            ///
+            /// ## Returns
+            /// * `Result<Self, PerseError>` - The recorded Transition
+            async fn record(
+                transaction: &mut Transaction<'_, Postgres>,
+                view_id: &Uuid,
+                transition: ViewScheduleTransitionTypes,
+                scheduled_for: sqlx::types::chrono::NaiveDateTime,
+            ) -> Result<Self, PerseError> {
+                query_as!(
+                    Self,
+                    "
+                    INSERT INTO view_schedule_transitions (view_id, transition, scheduled_for)
+                    VALUES ($1, $2, $3)
+                    RETURNING id, view_id, created_at, transition AS \"transition: ViewScheduleTransitionTypes\", scheduled_for
+                    ",
+                    view_id,
+                    transition as ViewScheduleTransitionTypes,
+                    scheduled_for,
+                )
+                .fetch_one(&mut **transaction)
+                .await
+                .map_err(|err| PerseError::new(ErrorTypes::InternalError, format!("Failed to record the scheduled Transition: {err}")))
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    // ## Create a top level View with the given route, returning the route it was given
+    async fn create(pool: &PgPool, route: &str) -> String {
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        let route: String = View::determine_unique_route(&mut transaction, route, None, None)
+            .await
+            .expect("Failed to determine a unique route.");
+
+        query("INSERT INTO views (visibility, title, route) VALUES ('VisibilityPublic', $1, $1)")
+            .bind(&route)
+            .execute(&mut *transaction)
+            .await
+            .expect("Failed to create the View.");
+        transaction.commit().await.expect("Failed to commit the transaction.");
+
+        route
+    }
+
+    #[sqlx::test]
+    async fn duplicate_routes_are_suffixed(pool: PgPool) {
+        assert_eq!(create(&pool, "about").await, "about");
+        assert_eq!(create(&pool, "about").await, "about-2");
+        assert_eq!(create(&pool, "about").await, "about-3");
+    }
+
+    #[sqlx::test]
+    async fn numeric_routes_are_not_suffixes(pool: PgPool) {
+        create(&pool, "release").await;
+        create(&pool, "release-2024").await;
+
+        assert_eq!(create(&pool, "release").await, "release-2");
+    }
+
+    #[sqlx::test]
+    async fn lowest_free_suffix_is_used(pool: PgPool) {
+        create(&pool, "about").await;
+        create(&pool, "about-3").await;
+
+        assert_eq!(create(&pool, "about").await, "about-2");
+        assert_eq!(create(&pool, "about").await, "about-4");
+    }
+
+    #[sqlx::test]
+    async fn long_routes_are_shortened_before_suffixing(pool: PgPool) {
+        let route: String = "a".repeat(254);
+
+        assert_eq!(create(&pool, &route).await, route);
+        assert_eq!(create(&pool, &route).await, format!("{}-2", "a".repeat(253)));
+        assert_eq!(create(&pool, &route).await, format!("{}-3", "a".repeat(253)));
+    }
+
+    // ## Create a top level View which is due to become the Homepage, returning its ID
+    async fn create_due_homepage(pool: &PgPool, route: &str, visibility: ViewVisibilityTypes, is_published: bool) -> Uuid {
+        query_scalar!(
+            "
+            INSERT INTO views (visibility, title, route, published_at, homepage_at)
+            VALUES ($1, $2, $2, CASE WHEN $3 THEN CURRENT_TIMESTAMP END, CURRENT_TIMESTAMP - INTERVAL '1 minute')
+            RETURNING id
+            ",
+            visibility as ViewVisibilityTypes,
+            route,
+            is_published,
+        )
+        .fetch_one(pool)
+        .await
+        .expect("Failed to create the View.")
+    }
+
+    // ## Apply the schedules that are due, returning whether the View became the Homepage
+    async fn becomes_homepage(pool: &PgPool, id: &Uuid) -> bool {
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        View::apply_schedules(&mut transaction).await.expect("Failed to apply the schedules.");
+        transaction.commit().await.expect("Failed to commit the transaction.");
+
+        query_scalar!("SELECT is_homepage FROM views WHERE id = $1", id)
+            .fetch_one(pool)
+            .await
+            .expect("Failed to retrieve the View.")
+    }
+
+    #[sqlx::test]
+    async fn scheduled_homepage_replaces_the_homepage(pool: PgPool) {
+        let id: Uuid = create_due_homepage(&pool, "welcome", ViewVisibilityTypes::VisibilityPublic, true).await;
+
+        assert!(becomes_homepage(&pool, &id).await);
+    }
+
+    #[sqlx::test]
+    async fn scheduled_homepage_skips_hidden_views(pool: PgPool) {
+        let id: Uuid = create_due_homepage(&pool, "secret", ViewVisibilityTypes::VisibilityHidden, true).await;
+
+        assert!(!becomes_homepage(&pool, &id).await);
+    }
+
+    #[sqlx::test]
+    async fn scheduled_homepage_skips_unpublished_views(pool: PgPool) {
+        let id: Uuid = create_due_homepage(&pool, "draft", ViewVisibilityTypes::VisibilityPublic, false).await;
+
+        assert!(!becomes_homepage(&pool, &id).await);
     }
 }
@@ -2,6 +2,15 @@ use parse_display::{Display, FromStr};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+// # Modules
+use crate::content::schema::{HeadElement, RenderedBody};
+use perse_utils::results::FieldErrors;
+
+/// # Routes reserved for Perse itself
+///
+/// Top level Views can't use these routes, as they would be hidden by the admin pages, API, and static files.
+pub const RESERVED_ROUTES: [&str; 5] = ["p", "api", "pkg", "assets", "favicon.ico"];
+
 /// # "View" model
 ///
 /// ## Fields
@@ -13,11 +22,21 @@ use validator::Validate;
 /// * `title` - Title of the View
 /// * `content_body` - Body content
 /// * `content_head` - Head Content
+/// * `content_format` - Format of the body content
 /// * `description` - Description of the View
 /// * `route` - Route of the View
 /// * `is_homepage` - Whether the View is the homepage
+/// * `deleted_at` - Deletion date of the View, if it has been moved to the trash
+/// * `published_at` - Last published date of the View. Views that have never been published are drafts.
+/// * `publish_at` - Date the View is scheduled to be published
+/// * `unpublish_at` - Date the View is scheduled to be unpublished
+/// * `homepage_at` - Date the View is scheduled to become the homepage
+/// * `parent_id` - ID of the View this View is nested under, if any
+/// * `path` - Full path of the View, made up of the routes of its parents and its own route
+/// * `tags` - Tags of the View, used to group Views such as in feeds
 // #[cfg(feature = "ssr")]
 #[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
 pub struct View {
     pub id: Option<uuid::Uuid>,
@@ -33,9 +52,33 @@ pub struct View {
     pub title: String,
     pub content_body: Option<String>,
     pub content_head: Option<String>,
+    pub content_format: ViewContentFormatTypes,
     pub description: Option<String>,
     pub route: String,
     pub is_homepage: bool,
+    #[cfg(feature = "ssr")]
+    pub deleted_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub deleted_at: Option<String>,
+    #[cfg(feature = "ssr")]
+    pub published_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub published_at: Option<String>,
+    #[cfg(feature = "ssr")]
+    pub publish_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub publish_at: Option<String>,
+    #[cfg(feature = "ssr")]
+    pub unpublish_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub unpublish_at: Option<String>,
+    #[cfg(feature = "ssr")]
+    pub homepage_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub homepage_at: Option<String>,
+    pub parent_id: Option<uuid::Uuid>,
+    pub path: String,
+    pub tags: Vec<String>,
 }
 
 impl Default for View {
@@ -52,9 +95,18 @@ impl Default for View {
             title: String::new(),
             content_body: None,
             content_head: None,
+            content_format: ViewContentFormatTypes::default(),
             description: None,
             route: String::new(),
             is_homepage: false,
+            deleted_at: None,
+            published_at: None,
+            publish_at: None,
+            unpublish_at: None,
+            homepage_at: None,
+            parent_id: None,
+            path: String::new(),
+            tags: Vec::new(),
         }
     }
 }
@@ -63,6 +115,7 @@ impl Default for View {
 ///
 /// The enum name's and serde's `rename_all` are important, and must match with the field's `name` in the View.
 #[derive(Deserialize, Serialize, PartialEq, FromStr, Display, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 #[serde(rename_all = "PascalCase")]
 #[display(style = "CamelCase")]
 #[cfg_attr(feature = "ssr", derive(sqlx::Type))]
@@ -76,6 +129,25 @@ pub enum ViewVisibilityTypes {
     VisibilityHidden,
 }
 
+/// # "ViewContentFormatTypes" model
+///
+/// The format of a View's body content, which determines how it is rendered.
+#[derive(Deserialize, Serialize, PartialEq, FromStr, Display, Clone, Copy, Default, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[serde(rename_all = "PascalCase")]
+#[display(style = "CamelCase")]
+#[cfg_attr(feature = "ssr", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "ssr",
+    sqlx(type_name = "content_format_types", rename_all = "PascalCase")
+)]
+pub enum ViewContentFormatTypes {
+    #[default]
+    FormatPlain,
+    FormatMarkdown,
+    FormatHtml,
+}
+
 /// # "NewView" request model
 ///
 /// The order, optional status, and type is important, and must match with the field order in the View.
@@ -86,23 +158,147 @@ pub enum ViewVisibilityTypes {
 /// * `title` - Title of the View
 /// * `content_body` - Body content
 /// * `content_head` - Head Content
+/// * `content_format` - Format of the body content
 /// * `description` - Description of the View
-/// * `route` - Route of the View
+/// * `route` - Route of the View, or blank to generate one from the title
+/// * `parent_id` - ID of the View to nest the View under, if any
+/// * `tags` - Tags of the View, as a comma separated list or a collection
 /// * `is_homepage` - Whether the View is the homepage
+/// * `is_draft` - Whether the View should be saved without publishing it
 #[derive(Deserialize, Serialize, Clone, Validate, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 pub struct NewView {
     pub visibility: ViewVisibilityTypes,
-    #[validate(length(min = 1, max = 255))]
+    #[validate(length(min = 1, max = 255, message = "The title must be between 1 and 255 characters long."))]
     pub title: String,
-    #[validate(length(min = 1, max = 255))]
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
     pub content_body: Option<String>,
-    #[validate(length(min = 1, max = 255))]
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
     pub content_head: Option<String>,
-    #[validate(length(min = 1, max = 255))]
+    #[serde(default)]
+    pub content_format: ViewContentFormatTypes,
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
+    #[validate(length(max = 255, message = "The description can't be longer than 255 characters."))]
     pub description: Option<String>,
-    #[validate(length(min = 1, max = 255))]
+    #[serde(default)]
+    #[validate(length(max = 255, message = "The route can't be longer than 255 characters."))]
     pub route: String,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub parent_id: Option<uuid::Uuid>,
+    #[serde(default, deserialize_with = "deserialize_tags")]
+    #[validate(length(max = 32, message = "A View can have at most 32 tags."))]
+    pub tags: Vec<String>,
     pub is_homepage: Option<String>,
+    pub is_draft: Option<String>,
+}
+
+impl NewView {
+    /// # Validate the `NewView`, collecting the messages for each invalid field
+    ///
+    /// These rules run in the browser before the form is submitted, and again on the server.
+    ///
+    /// ## Returns
+    /// * `FieldErrors` - The messages for each invalid field, which is empty if the `NewView` is valid
+    pub fn field_errors(&self) -> FieldErrors {
+        let mut fields: FieldErrors = self.validate().err().map(FieldErrors::from).unwrap_or_default();
+        let route: String = self.route_or_slug();
+
+        if route.is_empty() {
+            fields.add("route", "Enter a route, or a title to generate one from.");
+        }
+
+        view_field_errors(
+            &mut fields,
+            &self.title,
+            &route,
+            self.parent_id.as_ref(),
+            &self.visibility,
+            self.is_homepage.is_some(),
+        );
+
+        fields
+    }
+
+    /// # Get the route of the `NewView`, generating one from the title if it was left blank
+    ///
+    /// ## Returns
+    /// * `String` - The route
+    pub fn route_or_slug(&self) -> String {
+        match self.route.trim().is_empty() {
+            true => slugify(&self.title),
+            false => self.route.clone(),
+        }
+    }
+}
+
+// ## Validate the fields shared by new and updated Views
+//
+// Routes are a single lowercase slug, such as `about-me`, as Views are nested by choosing a parent View.
+// An empty route is left to the caller, as only a `NewView` can generate one.
+//
+// ### Fields
+// * `fields` - The messages for each invalid field, to add the messages to
+// * `title` - The title of the View
+// * `route` - The route of the View
+// * `parent_id` - The ID of the View's parent View, if it is nested
+// * `visibility` - The visibility of the View
+// * `is_homepage` - Whether the View is the homepage
+fn view_field_errors(
+    fields: &mut FieldErrors,
+    title: &str,
+    route: &str,
+    parent_id: Option<&uuid::Uuid>,
+    visibility: &ViewVisibilityTypes,
+    is_homepage: bool,
+) {
+    // Titles are trimmed before they are saved, so one made of whitespace is empty
+    if !title.is_empty() && title.trim().is_empty() {
+        fields.add("title", "The title can't be blank.");
+    }
+
+    if !route.is_empty() {
+        if route.starts_with('/') || route.ends_with('/') {
+            fields.add("route", "The route can't start or end with a slash.");
+        } else if route.contains('/') {
+            fields.add("route", "The route can't contain a slash, choose a parent View to nest it instead.");
+        }
+
+        if route.chars().any(|c| c.is_uppercase()) {
+            fields.add("route", "The route must be lowercase.");
+        }
+
+        if route.chars().any(|c| !(c.is_ascii_alphanumeric() || c == '-' || c == '/')) {
+            fields.add("route", "The route can only contain letters, numbers, and hyphens.");
+        }
+
+        if route.trim_matches('/').starts_with('-') || route.trim_matches('/').ends_with('-') || route.contains("--") {
+            fields.add("route", "The route can't start or end with a hyphen, or contain consecutive hyphens.");
+        }
+
+        if parent_id.is_none() && RESERVED_ROUTES.contains(&route.trim_matches('/').to_lowercase().as_str()) {
+            fields.add("route", format!("The route `{route}` is reserved for Perse."));
+        }
+    }
+
+    if let Some(message) = is_homepage.then(|| homepage_error(visibility)).flatten() {
+        fields.add("is_homepage", message);
+    }
+}
+
+/// # Check whether a View can be the Homepage
+///
+/// The Homepage is shown to everyone, so it can't be hidden.
+///
+/// ## Fields
+/// * `visibility` - The visibility of the View
+///
+/// ## Returns
+/// * `Option<&'static str>` - The reason the View can't be the Homepage, if any
+pub fn homepage_error(visibility: &ViewVisibilityTypes) -> Option<&'static str> {
+    match visibility {
+        ViewVisibilityTypes::VisibilityHidden => Some("A hidden View can't be the homepage."),
+        _ => None,
+    }
 }
 
 #[cfg(feature = "ssr")]
@@ -116,9 +312,749 @@ impl From<NewView> for View {
             title: view.title,
             content_body: view.content_body,
             content_head: view.content_head,
+            content_format: view.content_format,
+            description: view.description,
+            route: view.route,
+            is_homepage: view.is_homepage.is_some(),
+            deleted_at: None,
+            published_at: view
+                .is_draft
+                .is_none()
+                .then(|| sqlx::types::chrono::Utc::now().naive_utc()),
+            publish_at: None,
+            unpublish_at: None,
+            homepage_at: None,
+            parent_id: view.parent_id,
+            path: String::new(),
+            tags: view.tags,
+        }
+    }
+}
+
+/// # "ViewUpdate" request model
+///
+/// The order, optional status, and type is important, and must match with the field order in the View.
+///
+/// ## Fields
+///
+/// * `visibility` - Visibility of the View, as enum `ViewVisibilityTypes`
+/// * `title` - Title of the View
+/// * `content_body` - Body content
+/// * `content_head` - Head Content
+/// * `content_format` - Format of the body content
+/// * `description` - Description of the View
+/// * `route` - Route of the View
+/// * `parent_id` - ID of the View to nest the View under, if any
+/// * `tags` - Tags of the View, as a comma separated list or a collection
+/// * `is_homepage` - Whether the View is the homepage
+#[derive(Deserialize, Serialize, Clone, Validate, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct ViewUpdate {
+    pub visibility: ViewVisibilityTypes,
+    #[validate(length(min = 1, max = 255, message = "The title must be between 1 and 255 characters long."))]
+    pub title: String,
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
+    pub content_body: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
+    pub content_head: Option<String>,
+    #[serde(default)]
+    pub content_format: ViewContentFormatTypes,
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
+    #[validate(length(max = 255, message = "The description can't be longer than 255 characters."))]
+    pub description: Option<String>,
+    #[validate(length(min = 1, max = 255, message = "The route must be between 1 and 255 characters long."))]
+    pub route: String,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub parent_id: Option<uuid::Uuid>,
+    #[serde(default, deserialize_with = "deserialize_tags")]
+    #[validate(length(max = 32, message = "A View can have at most 32 tags."))]
+    pub tags: Vec<String>,
+    pub is_homepage: Option<String>,
+}
+
+impl ViewUpdate {
+    /// # Validate the `ViewUpdate`, collecting the messages for each invalid field
+    ///
+    /// The same rules as a `NewView` apply, except a route must be given.
+    ///
+    /// ## Returns
+    /// * `FieldErrors` - The messages for each invalid field, which is empty if the `ViewUpdate` is valid
+    pub fn field_errors(&self) -> FieldErrors {
+        let mut fields: FieldErrors = self.validate().err().map(FieldErrors::from).unwrap_or_default();
+
+        view_field_errors(
+            &mut fields,
+            &self.title,
+            &self.route,
+            self.parent_id.as_ref(),
+            &self.visibility,
+            self.is_homepage.is_some(),
+        );
+
+        fields
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<ViewUpdate> for View {
+    fn from(view: ViewUpdate) -> Self {
+        View {
+            id: None,
+            created_at: None,
+            updated_at: None,
+            visibility: view.visibility,
+            title: view.title,
+            content_body: view.content_body,
+            content_head: view.content_head,
+            content_format: view.content_format,
             description: view.description,
             route: view.route,
             is_homepage: view.is_homepage.is_some(),
+            deleted_at: None,
+            published_at: None,
+            publish_at: None,
+            unpublish_at: None,
+            homepage_at: None,
+            parent_id: view.parent_id,
+            path: String::new(),
+            tags: view.tags,
         }
     }
 }
+
+impl From<View> for ViewUpdate {
+    fn from(view: View) -> Self {
+        ViewUpdate {
+            visibility: view.visibility,
+            title: view.title,
+            content_body: view.content_body,
+            content_head: view.content_head,
+            content_format: view.content_format,
+            description: view.description,
+            route: view.route,
+            parent_id: view.parent_id,
+            tags: view.tags,
+            // Checkboxes submit "on" when checked
+            is_homepage: view.is_homepage.then(|| "on".to_string()),
+        }
+    }
+}
+
+/// # "ViewPatch" request model
+///
+/// The JSON body of a REST API update, which is applied to the View as it is now, before being converted to a
+/// `ViewUpdate`. Unlike the admin forms, whether the View is the homepage is a boolean rather than a checkbox value.
+///
+/// ## Fields
+///
+/// * `visibility` - Visibility of the View, as enum `ViewVisibilityTypes`
+/// * `title` - Title of the View
+/// * `content_body` - Body content
+/// * `content_head` - Head Content
+/// * `content_format` - Format of the body content
+/// * `description` - Description of the View
+/// * `route` - Route of the View
+/// * `parent_id` - ID of the View to nest the View under, if any
+/// * `tags` - Tags of the View
+/// * `is_homepage` - Whether the View is the homepage
+#[cfg(feature = "ssr")]
+#[derive(Deserialize, Serialize, Clone, Debug, utoipa::ToSchema)]
+pub struct ViewPatch {
+    pub visibility: ViewVisibilityTypes,
+    pub title: String,
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
+    pub content_body: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
+    pub content_head: Option<String>,
+    #[serde(default)]
+    pub content_format: ViewContentFormatTypes,
+    #[serde(default, deserialize_with = "deserialize_optional_text")]
+    pub description: Option<String>,
+    pub route: String,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
+    pub parent_id: Option<uuid::Uuid>,
+    #[serde(default, deserialize_with = "deserialize_tags")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub is_homepage: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl From<View> for ViewPatch {
+    fn from(view: View) -> Self {
+        ViewPatch {
+            visibility: view.visibility,
+            title: view.title,
+            content_body: view.content_body,
+            content_head: view.content_head,
+            content_format: view.content_format,
+            description: view.description,
+            route: view.route,
+            parent_id: view.parent_id,
+            tags: view.tags,
+            is_homepage: view.is_homepage,
+        }
+    }
+}
+
+#[cfg(feature = "ssr")]
+impl From<ViewPatch> for ViewUpdate {
+    fn from(view: ViewPatch) -> Self {
+        ViewUpdate {
+            visibility: view.visibility,
+            title: view.title,
+            content_body: view.content_body,
+            content_head: view.content_head,
+            content_format: view.content_format,
+            description: view.description,
+            route: view.route,
+            parent_id: view.parent_id,
+            tags: view.tags,
+            is_homepage: view.is_homepage.then(|| "on".to_string()),
+        }
+    }
+}
+
+/// # Deserialise an optional ID, such as the ID of a parent View
+///
+/// Forms submit an empty value when no View is chosen, which is treated as no ID.
+///
+/// ## Fields
+/// * `deserializer` - The deserializer in use
+///
+/// ## Returns
+/// * `Result<Option<uuid::Uuid>, D::Error>` - The ID, if any
+pub(crate) fn deserialize_optional_id<'de, D>(deserializer: D) -> Result<Option<uuid::Uuid>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)?.filter(|id| !id.is_empty()) {
+        Some(id) => uuid::Uuid::parse_str(&id)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+/// # Generate a route from a title
+///
+/// The title is transliterated to ASCII and lowercased, and each run of other characters becomes a single hyphen,
+/// so `Über uns!` becomes `uber-uns`. Long titles are shortened to whole words.
+///
+/// ## Fields
+/// * `title` - The title to generate the route from
+///
+/// ## Returns
+/// * `String` - The route, which is empty if the title has no letters or numbers
+pub fn slugify(title: &str) -> String {
+    const MAX_SLUG_LENGTH: usize = 200;
+
+    deunicode::deunicode(title)
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .fold(String::new(), |slug, word| match slug.is_empty() {
+            true => word.chars().take(MAX_SLUG_LENGTH).collect(),
+            false if slug.len() + 1 + word.len() <= MAX_SLUG_LENGTH => format!("{slug}-{word}"),
+            false => slug,
+        })
+}
+
+/// # Deserialise an optional text field of a View
+///
+/// Forms submit an empty value when a field is left blank, which is treated as no value.
+///
+/// ## Fields
+/// * `deserializer` - The deserializer in use
+///
+/// ## Returns
+/// * `Result<Option<String>, D::Error>` - The text, if any
+fn deserialize_optional_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|text| !text.trim().is_empty()))
+}
+
+/// # Deserialise the tags of a View
+///
+/// Forms submit tags as a single comma separated list, while other clients may submit a collection.
+/// Tags are trimmed, lowercased, and deduplicated.
+///
+/// ## Fields
+/// * `deserializer` - The deserializer in use
+///
+/// ## Returns
+/// * `Result<Vec<String>, D::Error>` - The tags
+fn deserialize_tags<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Tags {
+        List(String),
+        Collection(Vec<String>),
+    }
+
+    let tags: Vec<String> = match Tags::deserialize(deserializer)? {
+        Tags::List(list) => list.split(',').map(str::to_string).collect(),
+        Tags::Collection(tags) => tags,
+    };
+
+    let mut tags: Vec<String> = tags
+        .iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    tags.sort();
+    tags.dedup();
+
+    Ok(tags)
+}
+
+/// # "ViewDraft" model
+///
+/// The pending changes to a View, which are only served once they are published.
+///
+/// ## Fields
+///
+/// * `view_id` - ID of the View the Draft belongs to
+/// * `created_at` - Creation date of the Draft
+/// * `updated_at` - Last updated date of the Draft
+/// * `visibility` - Visibility of the View
+/// * `title` - Title of the View
+/// * `content_body` - Body content
+/// * `content_head` - Head Content
+/// * `content_format` - Format of the body content
+/// * `description` - Description of the View
+/// * `route` - Route of the View
+/// * `is_homepage` - Whether the View is the homepage
+/// * `parent_id` - ID of the View to nest the View under, if any
+/// * `tags` - Tags of the View
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct ViewDraft {
+    pub view_id: uuid::Uuid,
+    #[cfg(feature = "ssr")]
+    pub created_at: sqlx::types::chrono::NaiveDateTime,
+    #[cfg(not(feature = "ssr"))]
+    pub created_at: String,
+    #[cfg(feature = "ssr")]
+    pub updated_at: Option<sqlx::types::chrono::NaiveDateTime>,
+    #[cfg(not(feature = "ssr"))]
+    pub updated_at: Option<String>,
+    pub visibility: ViewVisibilityTypes,
+    pub title: String,
+    pub content_body: Option<String>,
+    pub content_head: Option<String>,
+    pub content_format: ViewContentFormatTypes,
+    pub description: Option<String>,
+    pub route: String,
+    pub is_homepage: bool,
+    pub parent_id: Option<uuid::Uuid>,
+    pub tags: Vec<String>,
+}
+
+impl ViewDraft {
+    /// # Apply the Draft's pending changes to its View
+    ///
+    /// The View's last updated date becomes the Draft's, so its rendered content isn't confused with the published View's.
+    ///
+    /// ## Fields
+    /// * `view` - The published View
+    ///
+    /// ## Returns
+    /// * `View` - The View as it will be once the Draft is published
+    pub fn apply(self, view: View) -> View {
+        View {
+            visibility: self.visibility,
+            title: self.title,
+            content_body: self.content_body,
+            content_head: self.content_head,
+            content_format: self.content_format,
+            description: self.description,
+            route: self.route,
+            is_homepage: self.is_homepage,
+            parent_id: self.parent_id,
+            tags: self.tags,
+            updated_at: self.updated_at.or(Some(self.created_at)),
+            ..view
+        }
+    }
+}
+
+/// # "RenderedView" model
+///
+/// A View prepared for rendering, with its head content parsed into the elements permitted in the document head,
+/// and its body content rendered in its format.
+///
+/// ## Fields
+///
+/// * `view` - The View
+/// * `head` - The permitted elements of the View's head content
+/// * `body` - The rendered body content
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+pub struct RenderedView {
+    pub view: View,
+    pub head: Vec<HeadElement>,
+    pub body: Option<RenderedBody>,
+}
+
+/// # "ViewRevision" model
+///
+/// A snapshot of a View, recorded by the database whenever a View is created or its content changes.
+///
+/// ## Fields
+///
+/// * `id` - ID of the Revision
+/// * `view_id` - ID of the View the Revision belongs to
+/// * `created_at` - Creation date of the Revision
+/// * `author` - Author of the Revision, if known
+/// * `visibility` - Visibility of the View
+/// * `title` - Title of the View
+/// * `content_body` - Body content
+/// * `content_head` - Head Content
+/// * `content_format` - Format of the body content
+/// * `description` - Description of the View
+/// * `route` - Route of the View
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct ViewRevision {
+    pub id: uuid::Uuid,
+    pub view_id: uuid::Uuid,
+    #[cfg(feature = "ssr")]
+    pub created_at: sqlx::types::chrono::NaiveDateTime,
+    #[cfg(not(feature = "ssr"))]
+    pub created_at: String,
+    pub author: Option<String>,
+    pub visibility: ViewVisibilityTypes,
+    pub title: String,
+    pub content_body: Option<String>,
+    pub content_head: Option<String>,
+    pub content_format: ViewContentFormatTypes,
+    pub description: Option<String>,
+    pub route: String,
+}
+
+/// # "ViewRevisionDiff" model
+///
+/// A single line of a line-by-line comparison between two Revisions.
+///
+/// ## Fields
+///
+/// * `change` - Whether the line was kept, added or removed
+/// * `content` - The content of the line
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+pub struct ViewRevisionDiff {
+    pub change: ViewRevisionDiffTypes,
+    pub content: String,
+}
+
+/// # "ViewRevisionDiffTypes" model
+#[derive(Deserialize, Serialize, PartialEq, Display, Clone, Debug)]
+#[display(style = "lowercase")]
+pub enum ViewRevisionDiffTypes {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+/// # "ViewSchedule" request model
+///
+/// Dates are in the `YYYY-MM-DDTHH:MM` format of a `datetime-local` input, and an empty date clears the schedule.
+///
+/// ## Fields
+///
+/// * `publish_at` - Date the View is scheduled to be published
+/// * `unpublish_at` - Date the View is scheduled to be unpublished
+/// * `homepage_at` - Date the View is scheduled to become the homepage
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ViewSchedule {
+    pub publish_at: Option<String>,
+    pub unpublish_at: Option<String>,
+    pub homepage_at: Option<String>,
+}
+
+/// # "ViewScheduleTransition" model
+///
+/// A scheduled change that has been applied to a View.
+///
+/// ## Fields
+///
+/// * `id` - ID of the Transition
+/// * `view_id` - ID of the View the Transition was applied to
+/// * `created_at` - Date the Transition was applied
+/// * `transition` - The change that was applied
+/// * `scheduled_for` - Date the Transition was scheduled for
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(sqlx::FromRow))]
+pub struct ViewScheduleTransition {
+    pub id: uuid::Uuid,
+    pub view_id: uuid::Uuid,
+    #[cfg(feature = "ssr")]
+    pub created_at: sqlx::types::chrono::NaiveDateTime,
+    #[cfg(not(feature = "ssr"))]
+    pub created_at: String,
+    pub transition: ViewScheduleTransitionTypes,
+    #[cfg(feature = "ssr")]
+    pub scheduled_for: sqlx::types::chrono::NaiveDateTime,
+    #[cfg(not(feature = "ssr"))]
+    pub scheduled_for: String,
+}
+
+/// # "ViewScheduleTransitionTypes" model
+///
+/// The enum name's and serde's `rename_all` are important, and must match with the database type.
+#[derive(Deserialize, Serialize, PartialEq, FromStr, Display, Clone, Debug)]
+#[serde(rename_all = "PascalCase")]
+#[display(style = "CamelCase")]
+#[cfg_attr(feature = "ssr", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "ssr",
+    sqlx(type_name = "schedule_transition_types", rename_all = "PascalCase")
+)]
+pub enum ViewScheduleTransitionTypes {
+    TransitionPublished,
+    TransitionUnpublished,
+    TransitionHomepage,
+}
+
+/// # "ViewSitemapEntry" model
+///
+/// A public View, as it is listed in the sitemap.
+///
+/// ## Fields
+///
+/// * `path` - Full path of the View
+/// * `is_homepage` - Whether the View is the homepage, which is listed at the root URL instead of its path
+/// * `updated_at` - Last updated date of the View, or its creation date if it has never been updated
+#[cfg(feature = "ssr")]
+#[derive(PartialEq, Clone, Debug)]
+pub struct ViewSitemapEntry {
+    pub path: String,
+    pub is_homepage: bool,
+    pub updated_at: sqlx::types::chrono::NaiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ## Build a valid `NewView` with the given route
+    fn new_view(route: &str) -> NewView {
+        NewView {
+            visibility: ViewVisibilityTypes::VisibilityPublic,
+            title: "About Me".to_string(),
+            content_body: None,
+            content_head: None,
+            content_format: ViewContentFormatTypes::default(),
+            description: None,
+            route: route.to_string(),
+            parent_id: None,
+            tags: Vec::new(),
+            is_homepage: None,
+            is_draft: None,
+        }
+    }
+
+    // ## Get the messages for a field of a `NewView`
+    fn errors(view: &NewView, field: &str) -> Vec<String> {
+        view.field_errors().get(field).unwrap_or_default()
+    }
+
+    #[test]
+    fn valid_view_has_no_errors() {
+        assert!(new_view("about-me-2").field_errors().is_empty());
+    }
+
+    #[test]
+    fn route_rejects_invalid_characters() {
+        assert_eq!(errors(&new_view("about_me!"), "route"), ["The route can only contain letters, numbers, and hyphens."]);
+        assert_eq!(errors(&new_view("über"), "route"), ["The route can only contain letters, numbers, and hyphens."]);
+    }
+
+    #[test]
+    fn route_must_be_lowercase() {
+        assert_eq!(errors(&new_view("About-Me"), "route"), ["The route must be lowercase."]);
+    }
+
+    #[test]
+    fn route_rejects_leading_and_trailing_slashes() {
+        for route in ["/about", "about/", "/about/"] {
+            assert_eq!(errors(&new_view(route), "route"), ["The route can't start or end with a slash."], "{route}");
+        }
+    }
+
+    #[test]
+    fn route_rejects_nested_paths() {
+        assert_eq!(
+            errors(&new_view("docs/install"), "route"),
+            ["The route can't contain a slash, choose a parent View to nest it instead."]
+        );
+    }
+
+    #[test]
+    fn route_must_be_a_slug() {
+        for route in ["-about", "about-", "about--me"] {
+            assert_eq!(
+                errors(&new_view(route), "route"),
+                ["The route can't start or end with a hyphen, or contain consecutive hyphens."],
+                "{route}"
+            );
+        }
+    }
+
+    #[test]
+    fn route_rejects_reserved_routes() {
+        for route in RESERVED_ROUTES {
+            assert!(errors(&new_view(route), "route").contains(&format!("The route `{route}` is reserved for Perse.")), "{route}");
+        }
+    }
+
+    #[test]
+    fn reserved_routes_can_be_nested() {
+        let view = NewView {
+            parent_id: Some(uuid::Uuid::nil()),
+            ..new_view("api")
+        };
+
+        assert!(view.field_errors().is_empty());
+    }
+
+    #[test]
+    fn title_cannot_be_blank() {
+        let view = NewView {
+            title: "   ".to_string(),
+            ..new_view("about")
+        };
+
+        assert_eq!(errors(&view, "title"), ["The title can't be blank."]);
+    }
+
+    #[test]
+    fn hidden_view_cannot_be_homepage() {
+        let view = NewView {
+            visibility: ViewVisibilityTypes::VisibilityHidden,
+            is_homepage: Some("on".to_string()),
+            ..new_view("home")
+        };
+
+        assert_eq!(errors(&view, "is_homepage"), ["A hidden View can't be the homepage."]);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn title_is_trimmed() {
+        use crate::PerseApiRequests;
+
+        let mut view = NewView {
+            title: "  About Me \n".to_string(),
+            ..new_view("about")
+        };
+        view.sanitise();
+
+        assert_eq!(view.title, "About Me");
+        assert!(view.is_valid().is_ok());
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn invalid_view_is_a_validation_error() {
+        use crate::PerseApiRequests;
+        use perse_utils::results::ErrorTypes;
+
+        let err = new_view("p").is_valid().unwrap_err();
+
+        assert_eq!(err.error_type(), ErrorTypes::Validation);
+        assert!(err.fields().get("route").is_some());
+    }
+
+    // ## Build a View, as it is retrieved from the database
+    fn view(route: &str, is_homepage: bool) -> View {
+        View {
+            visibility: ViewVisibilityTypes::VisibilityPublic,
+            title: "About Me".to_string(),
+            route: route.to_string(),
+            is_homepage,
+            ..View::default()
+        }
+    }
+
+    #[test]
+    fn update_round_trips_a_view() {
+        let update = ViewUpdate::from(view("about-me", true));
+
+        assert_eq!(update.route, "about-me");
+        assert_eq!(update.title, "About Me");
+        assert_eq!(update.is_homepage.as_deref(), Some("on"));
+        assert!(update.field_errors().is_empty());
+
+        assert_eq!(ViewUpdate::from(view("about-me", false)).is_homepage, None);
+    }
+
+    #[test]
+    fn update_applies_the_view_rules() {
+        let update = ViewUpdate {
+            visibility: ViewVisibilityTypes::VisibilityHidden,
+            ..ViewUpdate::from(view("api", true))
+        };
+
+        assert_eq!(update.field_errors().get("route"), Some(vec!["The route `api` is reserved for Perse.".to_string()]));
+        assert_eq!(update.field_errors().get("is_homepage"), Some(vec!["A hidden View can't be the homepage.".to_string()]));
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn patch_reads_the_homepage_as_a_boolean() {
+        let mut patch = serde_json::to_value(ViewPatch::from(view("about-me", false))).unwrap();
+        assert_eq!(patch["is_homepage"], serde_json::Value::Bool(false));
+
+        patch["is_homepage"] = serde_json::Value::Bool(true);
+        let update = ViewUpdate::from(serde_json::from_value::<ViewPatch>(patch.clone()).unwrap());
+        assert_eq!(update.is_homepage.as_deref(), Some("on"));
+
+        patch["is_homepage"] = serde_json::Value::String("on".to_string());
+        assert!(serde_json::from_value::<ViewPatch>(patch).is_err());
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn update_title_is_trimmed() {
+        use crate::PerseApiRequests;
+
+        let mut view = ViewUpdate {
+            title: "  About Me \n".to_string(),
+            ..ViewUpdate::from(view("about", false))
+        };
+        view.sanitise();
+
+        assert_eq!(view.title, "About Me");
+        assert!(view.is_valid().is_ok());
+    }
+
+    #[test]
+    fn slugify_transliterates_to_ascii() {
+        assert_eq!(slugify("Über uns!"), "uber-uns");
+    }
+
+    #[test]
+    fn slugify_collapses_separators() {
+        assert_eq!(slugify("  Hello,   World -- again!  "), "hello-world-again");
+    }
+
+    #[test]
+    fn slugify_shortens_to_whole_words() {
+        let slug: String = slugify(&"word ".repeat(60));
+
+        assert_eq!(slug.len(), 199);
+        assert!(slug.split('-').all(|word| word == "word"));
+        assert_eq!(slugify(&"a".repeat(250)), "a".repeat(200));
+    }
+
+    #[test]
+    fn slugify_is_empty_without_letters_or_numbers() {
+        assert_eq!(slugify("!!! --- ???"), "");
+        assert_eq!(slugify(""), "");
+    }
+}
@@ -1,36 +1,35 @@
 use parse_display::ParseError;
 use serde::{Deserialize, Serialize};
 use serde_json::Error;
+use std::{collections::BTreeMap, fmt};
 use server_fn::ServerFnError;
-use tracing::error;
+use tracing::{debug, error};
 use validator::ValidationErrors;
 
 /// # Successful API Responses
 #[derive(Deserialize, Serialize, Clone, Debug)]
-pub struct SuccessResponse {
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct SuccessResponse<T> {
     success: bool,
-    data: String,
+    data: T,
 }
 
-impl SuccessResponse {
+impl<T> SuccessResponse<T> {
     /// # Create a new success response
     ///
     /// ## Fields
-    /// * `data` - The data to return in the response, as a serialised string
+    /// * `data` - The data to return in the response
     ///
     /// ## Returns
-    /// * `SuccessResponse` - A success response
-    pub fn new(data: impl ToString) -> Self {
-        Self {
-            success: true,
-            data: data.to_string(),
-        }
+    /// * `SuccessResponse<T>` - A success response
+    pub fn new(data: T) -> Self {
+        Self { success: true, data }
     }
 }
 
 /// # SuccessResponse Conversions
 
-impl TryFrom<SuccessResponse> for String {
+impl<T: Serialize> TryFrom<SuccessResponse<T>> for String {
     type Error = Error;
 
     /// # Convert a `SuccessResponse` into a string
@@ -40,50 +39,142 @@ impl TryFrom<SuccessResponse> for String {
     ///
     /// ## Returns
     /// * `Result<String, Error>` - A result of the conversion
-    fn try_from(response: SuccessResponse) -> Result<Self, Self::Error> {
+    fn try_from(response: SuccessResponse<T>) -> Result<Self, Self::Error> {
         serde_json::to_string(&response)
     }
 }
 
 // # Error Results
 
-// Define the custom Perse error
+// Define the custom Perse error, sent to clients as JSON
 #[derive(Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
 pub struct PerseError {
+    #[serde(rename = "code")]
     error_type: ErrorTypes,
-    data: String,
+    status: u16,
+    message: String,
+    #[serde(default, skip_serializing_if = "FieldErrors::is_empty")]
+    fields: FieldErrors,
 }
 
-// Define the Perse error types
-#[derive(Deserialize, Serialize, Clone, Debug)]
+// Define the Perse error types, each with a machine-readable code
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
 pub enum ErrorTypes {
-    InternalError,
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
     Conflict,
     Validation,
+    InternalError,
 }
 
+// Define the validation errors of a request, as the messages for each invalid field
+#[derive(Deserialize, Serialize, Clone, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "ssr", derive(utoipa::ToSchema))]
+pub struct FieldErrors(BTreeMap<String, Vec<String>>);
+
 impl PerseError {
     // Create a new error
     pub fn new(error_type: ErrorTypes, message: impl ToString) -> Self {
-        // Build error message
-        let mut message_template = String::from("[Perse Error] ");
-
-        // Add the error type code
-        message_template += match error_type {
-            ErrorTypes::InternalError => "(500) ",
-            ErrorTypes::Conflict => "(409) ",
-            ErrorTypes::Validation => "(400) ",
-        };
+        let status: u16 = error_type.status_code();
+        let message: String = message.to_string();
 
-        // Format the logged messsage
-        message_template += &message.to_string();
-
-        // Log the message
-        error!("{}", message_template);
+        // Log the message, only treating server errors as errors, as client errors such as a missing page are expected
+        if status >= 500 {
+            error!("[Perse Error] ({status}) {message}");
+        } else {
+            debug!("[Perse Error] ({status}) {message}");
+        }
 
         Self {
             error_type,
-            data: message_template,
+            status,
+            message,
+            fields: FieldErrors::default(),
+        }
+    }
+
+    // Create a new validation error, with the messages for each invalid field
+    pub fn validation(fields: FieldErrors) -> Self {
+        Self {
+            fields: fields.clone(),
+            ..Self::new(ErrorTypes::Validation, fields)
+        }
+    }
+
+    // Get the type of the error
+    pub fn error_type(&self) -> ErrorTypes {
+        self.error_type
+    }
+
+    // Get the HTTP status code of the error
+    pub fn status_code(&self) -> u16 {
+        self.status
+    }
+
+    // Get the message of the error
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    // Get the validation errors of each field, if the request was invalid
+    pub fn fields(&self) -> &FieldErrors {
+        &self.fields
+    }
+}
+
+impl fmt::Display for PerseError {
+    // Display the message of the error
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl FieldErrors {
+    // Add a message for a field
+    pub fn add(&mut self, field: impl ToString, message: impl ToString) {
+        self.0.entry(field.to_string()).or_default().push(message.to_string());
+    }
+
+    // Get the messages for a field, if it is invalid
+    pub fn get(&self, field: &str) -> Option<Vec<String>> {
+        self.0.get(field).cloned()
+    }
+
+    // Check whether every field is valid
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for FieldErrors {
+    // Display the messages of each field, such as `title: The title is required.`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<String> = self
+            .0
+            .iter()
+            .map(|(field, messages)| format!("{field}: {}", messages.join(" ")))
+            .collect();
+
+        f.write_str(&fields.join("\n"))
+    }
+}
+
+impl ErrorTypes {
+    // Get the HTTP status code of the error type
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::BadRequest => 400,
+            Self::Unauthorized => 401,
+            Self::Forbidden => 403,
+            Self::NotFound => 404,
+            Self::Conflict => 409,
+            Self::Validation => 422,
+            Self::InternalError => 500,
         }
     }
 }
@@ -91,30 +182,72 @@ impl PerseError {
 // ## Error Conversions
 
 impl From<PerseError> for ServerFnError {
-    // Convert our `PerseError` into the Leptos `ServerFnError`
+    // Convert our `PerseError` into the Leptos `ServerFnError`, sent to the client as JSON
     fn from(err: PerseError) -> Self {
-        // Determine the best error type
-        let output: ServerFnError = match err.error_type {
-            ErrorTypes::InternalError => ServerFnError::ServerError(err.data),
-            ErrorTypes::Conflict => ServerFnError::ServerError(err.data),
-            ErrorTypes::Validation => ServerFnError::ServerError(err.data),
-        };
+        // Respond with the status of the error, when it is returned by a server function
+        #[cfg(feature = "ssr")]
+        if let Some(response) = leptos::use_context::<leptos_actix::ResponseOptions>() {
+            use actix_web::{http::{header, StatusCode}, HttpRequest};
+
+            // Pages, and forms submitted without JavaScript, are redirected back to the page instead
+            let accepts_html: bool = leptos::use_context::<HttpRequest>()
+                .and_then(|req| req.headers().get(header::ACCEPT).and_then(|accept| accept.to_str().ok()).map(|accept| accept.contains("text/html")))
+                .unwrap_or(false);
+
+            if !accepts_html {
+                response.set_status(StatusCode::from_u16(err.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR));
+            }
+        }
 
-        output
+        match serde_json::to_string(&err) {
+            Ok(data) => ServerFnError::ServerError(data),
+            Err(_) => ServerFnError::ServerError(err.message),
+        }
+    }
+}
+
+impl From<ServerFnError> for PerseError {
+    // Recover the `PerseError` returned by a server function, or describe any other `ServerFnError`
+    fn from(err: ServerFnError) -> Self {
+        match &err {
+            ServerFnError::ServerError(data) => serde_json::from_str(data).ok(),
+            _ => None,
+        }
+        .unwrap_or_else(|| Self {
+            error_type: ErrorTypes::InternalError,
+            status: ErrorTypes::InternalError.status_code(),
+            message: err.to_string(),
+            fields: FieldErrors::default(),
+        })
     }
 }
 
 impl From<ParseError> for PerseError {
     // Convert a `ParseError` into a `PerseError`
     fn from(err: ParseError) -> Self {
-        Self::new(ErrorTypes::Conflict, err.to_string())
+        Self::new(ErrorTypes::BadRequest, err.to_string())
     }
 }
 
 impl From<ValidationErrors> for PerseError {
-    // Convert a `ValidationErrors` into a `PerseError`
+    // Convert a `ValidationErrors` into a `PerseError`, keeping the messages for each field
     fn from(err: ValidationErrors) -> Self {
-        Self::new(ErrorTypes::Validation, err.to_string())
+        Self::validation(FieldErrors::from(err))
+    }
+}
+
+impl From<ValidationErrors> for FieldErrors {
+    // Collect the messages for each field of a `ValidationErrors`, falling back to the error code
+    fn from(err: ValidationErrors) -> Self {
+        let mut fields = Self::default();
+
+        for (field, errors) in err.field_errors() {
+            for error in errors {
+                fields.add(field.to_string(), error.message.as_ref().map(ToString::to_string).unwrap_or_else(|| error.to_string()));
+            }
+        }
+
+        fields
     }
 }
 
@@ -122,6 +255,9 @@ impl From<ValidationErrors> for PerseError {
 impl From<sqlx::Error> for PerseError {
     // Convert a sqlx `Error` into a `PerseError`
     fn from(err: sqlx::Error) -> Self {
-        Self::new(ErrorTypes::InternalError, format!("SQLx error: {}", err))
+        match err {
+            sqlx::Error::RowNotFound => Self::new(ErrorTypes::NotFound, "The record doesn't exist."),
+            err => Self::new(ErrorTypes::InternalError, format!("SQLx error: {}", err)),
+        }
     }
 }
@@ -0,0 +1,202 @@
+#[cfg(feature = "ssr")]
+use leptos::ServerFnError;
+#[cfg(feature = "ssr")]
+use perse_data::users::schema::{User, UserPermissions};
+#[cfg(feature = "ssr")]
+use perse_utils::results::PerseError;
+
+/// # Require a signed in User with a permission before running a server function
+///
+/// ## Fields
+/// * `permission` - The permission the server function requires
+///
+/// ## Returns
+/// * `Result<User, ServerFnError>` - The signed in User, or an Unauthorized or Forbidden error
+#[cfg(feature = "ssr")]
+pub async fn require_permission(permission: UserPermissions) -> Result<User, ServerFnError> {
+    let request: actix_web::HttpRequest = leptos_actix::extract().await?;
+
+    Ok(authorise_request(&request, permission).await?)
+}
+
+/// # Authenticate the User making a request, and ensure they have a permission
+///
+/// Requests with an `Authorization: Bearer` header are authenticated with an API Token instead of the session,
+/// and the API Token's scopes must include the permission as well.
+///
+/// ## Fields
+/// * `request` - The incoming request
+/// * `permission` - The permission the request requires
+///
+/// ## Returns
+/// * `Result<User, PerseError>` - The signed in User, or an Unauthorized or Forbidden error
+#[cfg(feature = "ssr")]
+pub async fn authorise_request(request: &actix_web::HttpRequest, permission: UserPermissions) -> Result<User, PerseError> {
+    authorise(perse_data::Database::get()?, request, permission).await
+}
+
+// ## Authenticate the User making a request with a database connection, and ensure they have a permission
+//
+// ### Fields
+// * `conn` - The database connection to use
+// * `request` - The incoming request
+// * `permission` - The permission the request requires
+//
+// ### Returns
+// * `Result<User, PerseError>` - The signed in User, or an Unauthorized or Forbidden error
+#[cfg(feature = "ssr")]
+async fn authorise(conn: &perse_data::DatabasePool, request: &actix_web::HttpRequest, permission: UserPermissions) -> Result<User, PerseError> {
+    use actix_session::SessionExt;
+    use actix_web::http::header;
+    use perse_data::{api_tokens::schema::ApiToken, users::schema::SESSION_USER_ID};
+    use perse_utils::results::ErrorTypes;
+    use sqlx::types::Uuid;
+
+    // Authenticate headless requests with their API Token
+    if let Some(authorization) = request.headers().get(header::AUTHORIZATION) {
+        let secret: &str = authorization
+            .to_str()
+            .ok()
+            .and_then(|authorization| authorization.strip_prefix("Bearer "))
+            .ok_or_else(|| PerseError::new(ErrorTypes::Unauthorized, "The Authorization header must contain a Bearer token."))?;
+        let (user, token): (User, ApiToken) = ApiToken::authenticate(conn, secret.trim()).await?;
+
+        // Ensure both the User's role and the API Token's scopes include the permission
+        user.authorise(permission)?;
+        token.authorise(permission)?;
+
+        return Ok(user);
+    }
+
+    // A session for a User that no longer exists is treated as signed out
+    let user: Option<User> = match request.get_session().get::<Uuid>(SESSION_USER_ID) {
+        Ok(Some(user_id)) => User::get_by_id(conn, &user_id).await?,
+        _ => None,
+    };
+    let user: User = user.ok_or_else(|| PerseError::new(ErrorTypes::Unauthorized, "You must be signed in to do this."))?;
+
+    // Ensure the User's role includes the permission
+    user.authorise(permission)?;
+
+    Ok(user)
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use actix_web::test;
+    use perse_data::{
+        api_tokens::schema::{ApiToken, ApiTokenScopes, NewApiToken},
+        DatabasePool,
+    };
+    use perse_utils::results::ErrorTypes;
+
+    // ## Create a User with a role
+    //
+    // ### Fields
+    // * `pool` - The database connection to use
+    // * `role` - Role of the User, as named in the database
+    async fn create_user(pool: &DatabasePool, role: &str) -> User {
+        sqlx::query_as("INSERT INTO users (username, password_hash, role) VALUES ($1, '', $1::user_role_types) RETURNING id, created_at, username, role")
+            .bind(role)
+            .fetch_one(pool)
+            .await
+            .expect("Failed to create the User.")
+    }
+
+    // ## Authorise a request made with a new API Token, returning the type of error if it is rejected
+    //
+    // ### Fields
+    // * `pool` - The database connection to use
+    // * `role` - Role of the User the API Token acts as
+    // * `scope` - Scope of the API Token
+    // * `permission` - The permission the request requires
+    async fn with_token(pool: &DatabasePool, role: &str, scope: ApiTokenScopes, permission: UserPermissions) -> Option<ErrorTypes> {
+        let user: User = create_user(pool, role).await;
+        let token = NewApiToken {
+            name: "Deploys".to_string(),
+            scopes: vec![scope],
+            expires_at: None,
+        };
+
+        let mut transaction = pool.begin().await.expect("Failed to start a transaction.");
+        let secret: String = ApiToken::create(&mut transaction, &user, &token).await.expect("Failed to issue the API Token.").secret;
+        transaction.commit().await.expect("Failed to commit the transaction.");
+
+        let request = test::TestRequest::default()
+            .insert_header(("Authorization", format!("Bearer {secret}")))
+            .to_http_request();
+
+        authorise(pool, &request, permission).await.err().map(|err| err.error_type())
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn api_tokens_need_both_the_role_and_the_scope(pool: DatabasePool) {
+        assert_eq!(with_token(&pool, "RolePublisher", ApiTokenScopes::ViewsWrite, UserPermissions::PublishViews).await, None);
+        // The scope permits publishing, but the role doesn't
+        assert_eq!(
+            with_token(&pool, "RoleEditor", ApiTokenScopes::ViewsWrite, UserPermissions::PublishViews).await,
+            Some(ErrorTypes::Forbidden)
+        );
+        // The role permits editing, but the scope doesn't
+        assert_eq!(
+            with_token(&pool, "RoleAdmin", ApiTokenScopes::ViewsRead, UserPermissions::EditViews).await,
+            Some(ErrorTypes::Forbidden)
+        );
+        // API Tokens can never manage the site
+        assert_eq!(
+            with_token(&pool, "RoleViewer", ApiTokenScopes::ViewsWrite, UserPermissions::ManageUsers).await,
+            Some(ErrorTypes::Forbidden)
+        );
+    }
+
+    // ## Authorise a request from a session, returning the type of error if it is rejected
+    //
+    // ### Fields
+    // * `pool` - The database connection to use
+    // * `user_id` - The ID of the User signed in to the session, if any
+    // * `permission` - The permission the request requires
+    async fn with_session(pool: &DatabasePool, user_id: Option<sqlx::types::Uuid>, permission: UserPermissions) -> Option<ErrorTypes> {
+        use actix_session::SessionExt;
+        use perse_data::users::schema::SESSION_USER_ID;
+
+        let request = test::TestRequest::default().to_http_request();
+        if let Some(user_id) = user_id {
+            request.get_session().insert(SESSION_USER_ID, user_id).expect("Failed to sign in.");
+        }
+
+        authorise(pool, &request, permission).await.err().map(|err| err.error_type())
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn sessions_are_authorised_by_role(pool: DatabasePool) {
+        let user: User = create_user(&pool, "RoleEditor").await;
+
+        assert_eq!(with_session(&pool, Some(user.id), UserPermissions::EditViews).await, None);
+        assert_eq!(with_session(&pool, Some(user.id), UserPermissions::PublishViews).await, Some(ErrorTypes::Forbidden));
+        assert_eq!(with_session(&pool, None, UserPermissions::ViewDrafts).await, Some(ErrorTypes::Unauthorized));
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn sessions_of_deleted_users_are_signed_out(pool: DatabasePool) {
+        let user: User = create_user(&pool, "RoleAdmin").await;
+
+        sqlx::query("DELETE FROM users WHERE id = $1")
+            .bind(user.id)
+            .execute(&pool)
+            .await
+            .expect("Failed to delete the User.");
+
+        assert_eq!(with_session(&pool, Some(user.id), UserPermissions::ViewDrafts).await, Some(ErrorTypes::Unauthorized));
+    }
+
+    #[sqlx::test(migrations = "../perse_data/migrations")]
+    async fn malformed_authorization_is_unauthorised(pool: DatabasePool) {
+        let request = test::TestRequest::default()
+            .insert_header(("Authorization", "Basic dXNlcjpwYXNz"))
+            .to_http_request();
+
+        let err: PerseError = authorise(&pool, &request, UserPermissions::ViewDrafts).await.expect_err("The request should be rejected.");
+        assert_eq!(err.error_type(), ErrorTypes::Unauthorized);
+    }
+}
@@ -0,0 +1,48 @@
+use leptos::{view, CollectView, IntoView};
+
+// # Modules
+use super::PerseComponent;
+use perse_data::content::schema::RenderedBody;
+
+// # Content Body Component
+pub struct ContentBody {}
+
+impl PerseComponent for ContentBody {
+    type InputType = RenderedBody;
+
+    /// # Build a Content Body component using the provided data
+    ///
+    /// ## Fields
+    /// * `value` - The `Option<Self::InputType>` to build the Content Body component
+    ///
+    /// ## Returns
+    /// * `impl IntoView` - The Content Body `PerseComponent` as a `Fragment`
+    fn build(value: Option<Self::InputType>) -> impl IntoView {
+        value.map(|body| match body {
+            RenderedBody::Text(text) => text.into_view(),
+            RenderedBody::Html(html) => view! { <div class="content_body" inner_html=html></div> }.into_view(),
+        })
+    }
+
+    /// # Build a collection of Content Body components using the provided data
+    ///
+    /// ## Fields
+    /// * `values` - The `Option<Vec<Self::InputType>>` to build the collection of Content Body components
+    ///
+    /// ## Returns
+    /// * `impl IntoView` - The Content Body `PerseComponent` as a `Fragment`
+    fn build_iter(values: Option<Vec<Self::InputType>>) -> impl IntoView {
+        values.map(|bodies| {
+            bodies
+                .into_iter()
+                .map(|body| Self::build(Some(body)))
+                .collect_view()
+        })
+    }
+
+    /// # Implement the Content Body component style
+    ///
+    /// ## Returns
+    /// * `Fragment` - The `PerseComponent` has no style
+    fn style() -> impl IntoView {}
+}
@@ -0,0 +1,61 @@
+use leptos::{view, CollectView, IntoView};
+
+// # Modules
+use super::{PerseComponent, PerseStyle};
+
+// # Field Errors Component
+pub struct FieldErrorList {}
+
+impl PerseComponent for FieldErrorList {
+    type InputType = String;
+
+    /// # Build a FieldErrorList component using the provided data
+    ///
+    /// ## Fields
+    /// * `value` - The `Option<Self::InputType>` validation message to build the FieldErrorList component
+    ///
+    /// ## Returns
+    /// * `impl IntoView` - The FieldErrorList `PerseComponent` as a `Fragment`
+    fn build(value: Option<Self::InputType>) -> impl IntoView {
+        Self::build_iter(value.map(|message| vec![message]))
+    }
+
+    /// # Build a FieldErrorList component for each validation message of a field
+    ///
+    /// ## Fields
+    /// * `values` - The `Option<Vec<Self::InputType>>` validation messages to build the FieldErrorList component
+    ///
+    /// ## Returns
+    /// * `impl IntoView` - The FieldErrorList `PerseComponent` as a `Fragment`
+    fn build_iter(values: Option<Vec<Self::InputType>>) -> impl IntoView {
+        values.map(|messages| view! {
+            {Self::style()}
+
+            <ul class="field_errors" role="alert">
+                {messages.into_iter().map(|message| view! {
+                    <li class="field_errors-item">{message}</li>
+                }).collect_view()}
+            </ul>
+        })
+    }
+
+    /// # Implement the FieldErrorList component style
+    ///
+    /// ## Returns
+    /// * `Fragment` - The `PerseComponent` as a `Fragment`
+    fn style() -> impl IntoView {
+        view! {{
+            let css: &str = "
+            .field_errors {
+                color: #b00020;
+                font-size: 0.875rem;
+                list-style: none;
+                margin: 0.25rem 0;
+                padding: 0;
+            }
+            ";
+
+            PerseStyle::from_css(css)
+        }}
+    }
+}
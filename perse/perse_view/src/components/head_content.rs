@@ -0,0 +1,82 @@
+use leptos::{view, Attribute, CollectView, IntoAttribute, IntoView};
+use leptos_meta::{Link, Meta, Script, Style};
+
+// # Modules
+use super::PerseComponent;
+use perse_data::content::schema::{HeadElement, HeadElementTypes};
+
+// # Head Content Component
+pub struct HeadContent {}
+
+// # Head Content Component
+impl HeadContent {
+    /// # Collect the permitted attributes of a Head Element
+    ///
+    /// ## Fields
+    /// * `element` - The `HeadElement` to collect the attributes of
+    ///
+    /// ## Returns
+    /// * `Vec<(&'static str, Attribute)>` - The attributes, ready to be applied to the element
+    fn attributes(element: &HeadElement) -> Vec<(&'static str, Attribute)> {
+        element
+            .attributes
+            .iter()
+            .filter_map(|(name, value)| {
+                element
+                    .tag
+                    .attribute(name)
+                    .map(|name| (name, value.clone().into_attribute()))
+            })
+            .collect()
+    }
+}
+
+impl PerseComponent for HeadContent {
+    type InputType = HeadElement;
+
+    /// # Build a Head Content component using the provided data
+    ///
+    /// The element is rendered into the document head using `leptos_meta`, so it is also managed during hydration.
+    /// Inline `<script>` and `<style>` elements are given the nonce of the current response.
+    ///
+    /// ## Fields
+    /// * `value` - The `Option<Self::InputType>` to build the Head Content component
+    ///
+    /// ## Returns
+    /// * `impl IntoView` - The Head Content `PerseComponent` as a `Fragment`
+    fn build(value: Option<Self::InputType>) -> impl IntoView {
+        value.map(|element| {
+            let attrs: Vec<(&'static str, Attribute)> = Self::attributes(&element);
+            let content: String = element.content.unwrap_or_default();
+
+            match element.tag {
+                HeadElementTypes::Meta => view! { <Meta attrs=attrs /> },
+                HeadElementTypes::Link => view! { <Link attrs=attrs /> },
+                HeadElementTypes::Script => view! { <Script attrs=attrs>{content}</Script> },
+                HeadElementTypes::Style => view! { <Style attrs=attrs>{content}</Style> },
+            }
+        })
+    }
+
+    /// # Build a collection of Head Content components using the provided data
+    ///
+    /// ## Fields
+    /// * `values` - The `Option<Vec<Self::InputType>>` to build the collection of Head Content components
+    ///
+    /// ## Returns
+    /// * `impl IntoView` - The Head Content `PerseComponent` as a `Fragment`
+    fn build_iter(values: Option<Vec<Self::InputType>>) -> impl IntoView {
+        values.map(|elements| {
+            elements
+                .into_iter()
+                .map(|element| Self::build(Some(element)))
+                .collect_view()
+        })
+    }
+
+    /// # Implement the Head Content component style
+    ///
+    /// ## Returns
+    /// * `Fragment` - The `PerseComponent` has no style
+    fn style() -> impl IntoView {}
+}
@@ -1,6 +1,9 @@
 use leptos::{view, IntoView};
 
 // # Modules
+pub mod content_body;
+pub mod field_errors;
+pub mod head_content;
 pub mod initial_state;
 pub mod loader;
 pub mod navbar;
@@ -28,7 +28,7 @@ impl PerseComponent for NavBar {
                 </a>
 
                 {value.map(|item| view! {
-                    <a href={format!("/{}", item.route)} title={item.title.clone()} aria-label={item.title.clone()} class="navbar-item">
+                    <a href={format!("/{}", item.path)} title={item.title.clone()} aria-label={item.title.clone()} class="navbar-item">
                         <span>{item.title}</span>
                     </a>
                 })}
@@ -53,7 +53,7 @@ impl PerseComponent for NavBar {
                 </a>
 
                 {values.map(|items| items.into_iter().map(|item| view! {
-                    <a href={format!("/{}", item.route)} title={item.title.clone()} aria-label={item.title.clone()} class="navbar-item">
+                    <a href={format!("/{}", item.path)} title={item.title.clone()} aria-label={item.title.clone()} class="navbar-item">
                         <span>{item.title}</span>
                     </a>
                 }).collect_view())}
@@ -18,11 +18,11 @@ impl ViewList {
     /// * `impl IntoView` - The link as a `Fragment`
     fn build_link(view: View) -> impl IntoView {
         view! {
-            <a href={format!("/{}", view.route)} title={view.title.clone()} aria-label={view.title.clone()}>
+            <a href={format!("/{}", view.path)} title={view.title.clone()} aria-label={view.title.clone()}>
             {
                 format!(
-                    "/{} ({}), {}{}",
-                    view.route,
+                    "/{} ({}), {}{}{}",
+                    view.path,
                     view.title,
                     match view.visibility {
                         ViewVisibilityTypes::VisibilityPublic => "(Public)",
@@ -33,9 +33,18 @@ impl ViewList {
                         true => ", (Homepage)",
                         false => "",
                     },
+                    match view.published_at {
+                        Some(_) => "",
+                        None => ", (Draft)",
+                    },
                 )
             }
             </a>
+            {view.id.map(|id| view! {
+                <a href={format!("/p/edit/{id}")} title="Edit" aria-label={format!("Edit {}", view.title)} class="view_list-action">
+                    "Edit"
+                </a>
+            })}
         }
     }
 }
@@ -6,5 +6,8 @@ pub mod views;
 /// ## Components
 pub mod components;
 
+/// ## Authentication
+pub mod auth;
+
 /// # Define the application name for using in the UI
 pub const APP_NAME: &str = "perse";
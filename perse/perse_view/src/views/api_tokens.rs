@@ -0,0 +1,246 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::api_tokens::schema::{ApiToken, ApiTokenScopes, IssuedApiToken, NewApiToken};
+use perse_utils::results::PerseError;
+
+// # Components
+use crate::components::{
+    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    PerseComponent,
+};
+use crate::APP_NAME;
+
+/// # View for the "API Tokens"
+#[component]
+pub fn ApiTokens() -> impl IntoView {
+    // ## Server Functions
+
+    // Create a Frontend API for the New API Token request
+    let new_api_token_api = Action::<NewApiTokenHandler, _>::server();
+
+    // Create a Frontend API for the Revoke API Token request
+    let revoke_api_token_api = Action::<RevokeApiTokenHandler, _>::server();
+
+    // Create a Server API for the Get API Tokens request
+    create_server_action::<GetApiTokensHandler>();
+
+    // ## Signals
+
+    // Signal for the new and revoke API Token responses
+    let new_api_token_signal = Signal::derive(move || new_api_token_api.value().get());
+    let revoke_api_token_signal = Signal::derive(move || revoke_api_token_api.value().get());
+
+    // ### Get API Tokens signal
+
+    // Resource for tracking the Get API Tokens signal, refreshed after every change
+    let get_api_tokens_signal_resource = create_resource(
+        // Signal source
+        move || (new_api_token_api.version().get(), revoke_api_token_api.version().get()),
+        // Loader
+        |_signal_count| async move { get_api_tokens().await },
+    );
+
+    // Action for the Get API Tokens signal
+    let get_api_tokens_signal_action = move || get_api_tokens_signal_resource.get();
+
+    // ## Views
+
+    // Components
+    let loader = move || Loader::build(None).into_view();
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="API Tokens | Perse" />
+        <Meta name="description" content="Manage the API tokens of a Perse user" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"API Tokens"</h1></header>
+
+                <main>
+                    <p>"API tokens act as you when sent in an "<code>"Authorization: Bearer"</code>" header to the API, limited to their scopes."</p>
+
+                    <ActionForm action=new_api_token_api>
+                        <div>
+                            <div>
+                                <label for="name">"Name"<sup> "*"</sup></label>
+                                <input id="name" name="data[name]" type="text" placeholder="Deploy script" autocomplete="off" required />
+                            </div>
+                            <fieldset>
+                                <legend>"Scopes"<sup> "*"</sup></legend>
+                                {[ApiTokenScopes::ViewsRead, ApiTokenScopes::ViewsWrite]
+                                    .into_iter()
+                                    .map(|scope| view! {
+                                        <label>
+                                            <input name="data[scopes][]" type="checkbox" value=scope.to_string() />
+                                            {scope.to_string()}
+                                        </label>
+                                    })
+                                    .collect_view()}
+                            </fieldset>
+                            <div>
+                                <label for="expires_at">"Expires on"</label>
+                                <input id="expires_at" name="data[expires_at]" type="date" />
+                            </div>
+                            <br />
+                        </div>
+
+                        <div>
+                            <button type="submit" aria-label="Issue API Token">"Issue"</button>
+                            <br />
+                        </div>
+                    </ActionForm>
+
+                    <aside>
+                        // Action for the New API Token signal
+                        {move || new_api_token_signal.get().map(|response| {
+                            response.map(|issued| view! {
+                                <p>{format!("{} has been issued. Copy its secret now, as it won't be shown again.", issued.token.name)}</p>
+                                <p><code>{issued.secret}</code></p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+
+                        // Action for the Revoke API Token signal
+                        {move || revoke_api_token_signal.get().map(|response| {
+                            response.map(|_| view! {
+                                <p>"The API token has been revoked."</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+                    </aside>
+
+                    <Transition fallback=loader>
+                        <section>
+                            // Action for the Get API Tokens signal
+                            {move || get_api_tokens_signal_action().map(|response| {
+                                // View for the Get API Tokens result
+                                response.map(|tokens| view! {
+                                    <ul class="view_list">
+                                        {tokens.into_iter().map(|token| view! {
+                                            <li class="view_list-item">
+                                                <span>{token.name}</span>
+                                                <code>{format!("{}…", token.token_prefix)}</code>
+                                                <span>{token.scopes.iter().map(ApiTokenScopes::to_string).collect::<Vec<_>>().join(", ")}</span>
+                                                <span>{format!(
+                                                    "Expires: {}",
+                                                    token.expires_at.map(|date| date.to_string()).unwrap_or("never".to_string()),
+                                                )}</span>
+                                                <span>{format!(
+                                                    "Last used: {}",
+                                                    token.last_used_at.map(|date| date.to_string()).unwrap_or("never".to_string()),
+                                                )}</span>
+
+                                                <ActionForm action=revoke_api_token_api>
+                                                    <input name="id" type="hidden" value=token.id.to_string() />
+                                                    <button type="submit" aria-label="Revoke API Token">"Revoke"</button>
+                                                </ActionForm>
+                                            </li>
+                                        }).collect_view()}
+                                    </ul>
+                                }.into_view())
+                                // View for the server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <p>{PerseError::from(err).to_string()}</p>
+                                }.into_view())
+                            })
+                            // Initial state
+                            .unwrap_or_else(|| {
+                                Some(InitialState::build(None)).collect_view()
+                            })}
+                        </section>
+                    </Transition>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Retrieve the collection of the signed in User's `ApiToken` records from the database
+///
+/// ## Returns
+/// * `Result<Vec<ApiToken>, ServerFnError>` - A list of API Tokens, newest first
+#[server(name = GetApiTokensHandler, prefix = "/api/v1", endpoint = "tokens")]
+async fn get_api_tokens() -> Result<Vec<ApiToken>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::ManageApiTokens).await?;
+
+    // Get the User's API Tokens
+    Ok(ApiToken::get_all_by_user(Database::get()?, &user.id).await?)
+}
+
+/// # Issue a new `ApiToken` for the signed in User
+///
+/// ## Fields
+/// * `data` - The data to issue a new `ApiToken` with
+///
+/// ## Returns
+/// * `Result<IssuedApiToken, ServerFnError>` - The new API Token, along with its secret
+#[server(name = NewApiTokenHandler, prefix = "/api/v1", endpoint = "tokens/new")]
+async fn new_api_token(data: NewApiToken) -> Result<IssuedApiToken, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseApiRequests};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::ManageApiTokens).await?;
+
+    // Declare mutable, sanitise, and run Request & Custom validation
+    let mut data: NewApiToken = data;
+    data.sanitise();
+    data.is_valid()?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Issue the new API Token
+    let data: IssuedApiToken = ApiToken::create(&mut transaction, &user, &data).await?;
+
+    // Commit the transaction and return the new API Token
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Revoke one of the signed in User's `ApiToken` records
+///
+/// ## Fields
+/// * `id` - The ID of the `ApiToken` record to revoke
+///
+/// ## Returns
+/// * `Result<(), ServerFnError>` - Returns void
+#[server(name = RevokeApiTokenHandler, prefix = "/api/v1", endpoint = "tokens/revoke")]
+async fn revoke_api_token(id: String) -> Result<(), ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::ManageApiTokens).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Revoke the API Token
+    ApiToken::revoke(&mut transaction, &user.id, &id).await?;
+
+    // Commit the transaction
+    transaction.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,600 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::views::schema::{
+    View as PerseView, ViewContentFormatTypes, ViewDraft, ViewSchedule, ViewUpdate, ViewVisibilityTypes,
+};
+use perse_utils::results::PerseError;
+
+// # Modules
+use super::{errors::NotFound, new::get_all_views};
+
+// # Components
+use crate::components::{
+    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    view_list::ViewList, PerseComponent,
+};
+use crate::APP_NAME;
+
+/// # Edit View Request Parameters
+#[derive(Params, Clone, Debug, PartialEq, Eq)]
+struct EditParams {
+    id: Option<String>,
+}
+
+/// # View for "Edit View"
+#[component]
+pub fn Edit() -> impl IntoView {
+    let query = use_params::<EditParams>();
+    let requested_id = move || {
+        query.with(|q| {
+            q.as_ref()
+                .map(|q| q.id.clone().unwrap_or_default())
+                .unwrap_or_default()
+        })
+    };
+
+    // ## Server Functions
+
+    // Create a Server API for the Update View request
+    create_server_action::<UpdateViewHandler>();
+
+    // Create a Frontend API for the Save Draft request
+    let save_draft_api = Action::<SaveDraftHandler, _>::server();
+
+    // Create a Frontend API for the Publish View request
+    let publish_view_api = Action::<PublishViewHandler, _>::server();
+
+    // Create a Frontend API for the Preview Link request
+    let preview_link_api = Action::<PreviewLinkHandler, _>::server();
+
+    // Create a Frontend API for the Schedule View request
+    let schedule_view_api = Action::<ScheduleViewHandler, _>::server();
+
+    // Create a Frontend API for the Delete View request
+    let delete_view_api = Action::<DeleteViewHandler, _>::server();
+
+    // Create a Server API for the Get View request
+    create_server_action::<GetViewHandler>();
+
+    // ## Signals
+
+    // Signal for the save draft response
+    let save_draft_signal = Signal::derive(move || save_draft_api.value().get());
+
+    // ### Save Draft signal
+
+    // Resource for tracking the Save Draft signal
+    let save_draft_signal_resource = create_resource(
+        // Signal source
+        save_draft_signal,
+        // Loader
+        |save_draft_response| async move { save_draft_response },
+    );
+
+    // Action for the Save Draft signal
+    let save_draft_signal_action = move || save_draft_signal_resource.get().flatten();
+
+    // ### Publish View and Preview Link signals
+
+    // Signal for the publish view and preview link responses
+    let publish_view_signal = Signal::derive(move || publish_view_api.value().get());
+    let preview_link_signal = Signal::derive(move || preview_link_api.value().get());
+
+    // Signal for the schedule view response
+    let schedule_view_signal = Signal::derive(move || schedule_view_api.value().get());
+
+    // ### Delete View signal
+
+    // Signal for the delete view response
+    let delete_view_signal = Signal::derive(move || delete_view_api.value().get());
+
+    // ### Get View signal
+
+    // Resource for tracking the Get View signal, refreshed after every save or publish
+    let get_view_signal_resource = create_resource(
+        // Signal source
+        move || (requested_id(), save_draft_api.version().get(), publish_view_api.version().get()),
+        // Loader
+        |(id, _, _)| async move { get_view(id).await },
+    );
+
+    // Action for the Get View signal
+    let get_view_signal_action = move || get_view_signal_resource.get();
+
+    // ### Get Views signal
+
+    // Resource for tracking the Get Views signal, used to choose a parent View
+    let get_all_views_signal_resource = create_resource(
+        // Signal source
+        move || publish_view_api.version().get(),
+        // Loader
+        |_signal_count| async move { get_all_views().await },
+    );
+
+    // Action for the Get Views signal
+    let views_list_signal_action = move || get_all_views_signal_resource.get();
+
+    // ## Views
+
+    // Components
+    let loader = move || Loader::build(None).into_view();
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Edit View | Perse" />
+        <Meta name="description" content="Edit an existing Perse view" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"Edit View"</h1></header>
+
+                <main>
+                    <Transition fallback=loader>
+                        // Action for the Get View signal
+                        {move || get_view_signal_action().map(|response| response.map(|(view, has_draft)| view! {
+                            {match (has_draft, view.published_at.is_some()) {
+                                (true, _) => view! { <p><strong>"This view has unpublished changes."</strong></p> }.into_view(),
+                                (false, false) => view! { <p><strong>"This view has not been published yet."</strong></p> }.into_view(),
+                                (false, true) => ().into_view(),
+                            }}
+
+                            <ActionForm action=save_draft_api>
+                                <input name="id" type="hidden" value=view.id.map(|id| id.to_string()).unwrap_or_default() />
+
+                                <div>
+                                    <div>
+                                        <label for="visibility">"Visibility"</label>
+                                        <select id="visibility" name="data[visibility]">
+                                            <option value="VisibilityPublic" selected=view.visibility == ViewVisibilityTypes::VisibilityPublic>"Public"</option>
+                                            <option value="VisibilityHidden" selected=view.visibility == ViewVisibilityTypes::VisibilityHidden>"Hidden"</option>
+                                            <option value="VisibilityUnlisted" selected=view.visibility == ViewVisibilityTypes::VisibilityUnlisted>"Unlisted"</option>
+                                        </select>
+                                    </div>
+                                    <div>
+                                        <label for="title">"Title"<sup> "*"</sup></label>
+                                        <input id="title" name="data[title]" type="text" placeholder="About Me" value=view.title required />
+                                    </div>
+                                    <div>
+                                        <label for="content_body">"Body Content"</label>
+                                        <textarea id="content_body" name="data[content_body]" placeholder="It was a bright cold day in April...">{view.content_body}</textarea>
+                                    </div>
+                                    <div>
+                                        <label for="content_format">"Body Format"</label>
+                                        <select id="content_format" name="data[content_format]">
+                                            <option value="FormatPlain" selected=view.content_format == ViewContentFormatTypes::FormatPlain>"Plain text"</option>
+                                            <option value="FormatMarkdown" selected=view.content_format == ViewContentFormatTypes::FormatMarkdown>"Markdown"</option>
+                                            <option value="FormatHtml" selected=view.content_format == ViewContentFormatTypes::FormatHtml>"HTML"</option>
+                                        </select>
+                                    </div>
+                                    <div>
+                                        <label for="content_head">"Head Content"</label>
+                                        <textarea id="content_head" name="data[content_head]" placeholder="">{view.content_head}</textarea>
+                                    </div>
+                                    <div>
+                                        <label for="description">"Description"</label>
+                                        <textarea id="description" name="data[description]" placeholder="">{view.description}</textarea>
+                                    </div>
+                                    <div>
+                                        <label for="parent_id">"Parent View"</label>
+                                        <select id="parent_id" name="data[parent_id]">
+                                            <option value="">"None (top level)"</option>
+                                            // Action for the Get Views signal, leaving out the View being edited
+                                            {move || views_list_signal_action().and_then(Result::ok).map(|views| views
+                                                .into_iter()
+                                                .filter(|other| other.id != view.id)
+                                                .filter_map(|other| other.id.map(|id| view! {
+                                                    <option value=id.to_string() selected=view.parent_id == Some(id)>
+                                                        {format!("/{} ({})", other.path, other.title)}
+                                                    </option>
+                                                }))
+                                                .collect_view())}
+                                        </select>
+                                    </div>
+                                    <div>
+                                        <label for="route">"Route"</label>
+                                        <input id="route" name="data[route]" type="text" placeholder="about-me" value=view.route required />
+                                    </div>
+                                    <div>
+                                        <label for="tags">"Tags"</label>
+                                        <input id="tags" name="data[tags]" type="text" placeholder="news, releases" value=view.tags.join(", ") />
+                                    </div>
+                                    <br />
+                                    <div>
+                                        <label for="is_homepage">"Is this the homepage?"</label>
+                                        <input id="is_homepage" name="data[is_homepage]" type="checkbox" checked=view.is_homepage />
+                                    </div>
+                                    <br />
+                                </div>
+
+                                <div>
+                                    <button type="submit" aria-label="Save Draft">"Save draft"</button>
+                                    <br />
+                                </div>
+                            </ActionForm>
+
+                            <ActionForm action=publish_view_api>
+                                <input name="id" type="hidden" value=view.id.map(|id| id.to_string()).unwrap_or_default() />
+                                <button type="submit" aria-label="Publish View">"Publish"</button>
+                            </ActionForm>
+
+                            <ActionForm action=preview_link_api>
+                                <input name="id" type="hidden" value=view.id.map(|id| id.to_string()).unwrap_or_default() />
+                                <button type="submit" aria-label="Create a Preview link">"Preview"</button>
+                            </ActionForm>
+
+                            <ActionForm action=schedule_view_api>
+                                <input name="id" type="hidden" value=view.id.map(|id| id.to_string()).unwrap_or_default() />
+
+                                <div>
+                                    <div>
+                                        <label for="publish_at">"Publish at"</label>
+                                        <input id="publish_at" name="data[publish_at]" type="datetime-local" value=view.publish_at.map(|date| date.to_string()) />
+                                    </div>
+                                    <div>
+                                        <label for="unpublish_at">"Unpublish at"</label>
+                                        <input id="unpublish_at" name="data[unpublish_at]" type="datetime-local" value=view.unpublish_at.map(|date| date.to_string()) />
+                                    </div>
+                                    <div>
+                                        <label for="homepage_at">"Make the homepage at"</label>
+                                        <input id="homepage_at" name="data[homepage_at]" type="datetime-local" value=view.homepage_at.map(|date| date.to_string()) />
+                                    </div>
+                                </div>
+
+                                <div>
+                                    <button type="submit" aria-label="Schedule View">"Schedule"</button>
+                                </div>
+                            </ActionForm>
+
+                            <a href=format!("/p/revisions/{}", view.id.map(|id| id.to_string()).unwrap_or_default()) title="Revisions" aria-label="Revisions">"View revisions"</a>
+
+                            <ActionForm action=delete_view_api>
+                                <input name="id" type="hidden" value=view.id.map(|id| id.to_string()).unwrap_or_default() />
+                                <button type="submit" aria-label="Move View to the trash">"Delete"</button>
+                            </ActionForm>
+                        }.into_view())
+                        // View for the server error, or if the requested View does not exist
+                        .unwrap_or_else(|err| view! { <NotFound err=Some(err) /> }.into_view()))
+                        // Initial state
+                        .unwrap_or_else(|| Some(InitialState::build(None)).collect_view())}
+                    </Transition>
+
+                    <aside>
+                        <Transition fallback=loader>
+                            <br />
+                            // Action for the Save Draft signal
+                            {move || save_draft_signal_action().map(|response| {
+                                // View for the Save Draft result
+                                response.map(|_draft| view! {
+                                    <header><h2>"Success"</h2></header>
+                                    <main>
+                                        <p>"Your draft has been saved! Publish it to make your changes live."</p>
+                                    </main>
+                                }.into_view())
+                                // View for the Save Draft server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <main>
+                                        <p>{PerseError::from(err).to_string()}</p>
+                                    </main>
+                                }.into_view())
+                            })
+                            // Initial state
+                            .unwrap_or_else(|| {
+                                Some(InitialState::build(None)).collect_view()
+                            })}
+
+                            // Action for the Publish View signal
+                            {move || publish_view_signal.get().map(|response| {
+                                // View for the Publish View result
+                                response.map(|view| view! {
+                                    <header><h2>"Published"</h2></header>
+                                    <main>
+                                        <p>"Your view has been published!"</p>
+                                        <main>
+                                            {ViewList::build(Some(view))}
+                                        </main>
+                                    </main>
+                                }.into_view())
+                                // View for the Publish View server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <main>
+                                        <p>{PerseError::from(err).to_string()}</p>
+                                    </main>
+                                }.into_view())
+                            })}
+
+                            // Action for the Preview Link signal
+                            {move || preview_link_signal.get().map(|response| {
+                                // View for the Preview Link result
+                                response.map(|link| view! {
+                                    <header><h2>"Preview"</h2></header>
+                                    <main>
+                                        <p>"Anyone with this link can preview your unpublished changes until it expires."</p>
+                                        <p><a href=link.clone() target="_blank">{link.clone()}</a></p>
+                                    </main>
+                                }.into_view())
+                                // View for the Preview Link server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <main>
+                                        <p>{PerseError::from(err).to_string()}</p>
+                                    </main>
+                                }.into_view())
+                            })}
+
+                            // Action for the Schedule View signal
+                            {move || schedule_view_signal.get().map(|response| {
+                                // View for the Schedule View result
+                                response.map(|_view| view! {
+                                    <header><h2>"Scheduled"</h2></header>
+                                    <main>
+                                        <p>"Your schedule has been saved!"</p>
+                                    </main>
+                                }.into_view())
+                                // View for the Schedule View server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <main>
+                                        <p>{PerseError::from(err).to_string()}</p>
+                                    </main>
+                                }.into_view())
+                            })}
+
+                            // Action for the Delete View signal
+                            {move || delete_view_signal.get().map(|response| {
+                                // View for the Delete View result
+                                response.map(|_view| view! {
+                                    <header><h2>"Deleted"</h2></header>
+                                    <main>
+                                        <p>"Your view has been moved to the " <a href="/p/trash">"trash"</a> "."</p>
+                                    </main>
+                                }.into_view())
+                                // View for the Delete View server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <main>
+                                        <p>{PerseError::from(err).to_string()}</p>
+                                    </main>
+                                }.into_view())
+                            })}
+                        </Transition>
+                    </aside>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Update an existing `View` record in the database
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to update
+/// * `data` - The data to update the `View` record with
+///
+/// ## Returns
+/// * `Result<PerseView, ServerFnError>` - The successful response as a Perse View
+#[server(name = UpdateViewHandler, prefix = "/api/v1", endpoint = "views/update")]
+async fn update_view(id: String, data: ViewUpdate) -> Result<PerseView, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseApiRequests, PerseDatabaseModels};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::EditViews).await?;
+
+    // Declare mutable, sanitise, and run Request & Custom validation
+    let mut data: ViewUpdate = data;
+    data.sanitise();
+    data.is_valid()?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Ensure the User can update the View, locking it until the changes are committed
+    let view: PerseView = PerseView::get_by_id_for_update(&mut transaction, &id).await?;
+    user.authorise_view_update(&view, &data)?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Determine the URL path
+    data.route = ViewUpdate::determine_url_path(&mut transaction, &id, &data).await?;
+
+    // Update the View
+    let data: PerseView = PerseView::update(&mut transaction, &id, &data.into()).await?;
+
+    // Commit the transaction and return the updated View
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Retrieve a `View` record from the database by ID, with its Draft applied
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to retrieve
+///
+/// ## Returns
+/// * `Result<(PerseView, bool), ServerFnError>` - The requested View, and whether it has a Draft
+#[server(name = GetViewHandler, prefix = "/api/v1", endpoint = "views/get")]
+async fn get_view(id: String) -> Result<(PerseView, bool), ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseDatabaseModels};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ViewDrafts).await?;
+
+    // Get a database connection
+    let conn = Database::get()?;
+
+    // Get the View using the requested ID
+    let view: PerseView = PerseView::get_by_id(conn, &id).await?;
+
+    // Apply the View's Draft, if it has one
+    Ok(match ViewDraft::get_by_view_id(conn, &view.id.unwrap_or_default()).await? {
+        Some(draft) => (draft.apply(view), true),
+        None => (view, false),
+    })
+}
+
+/// # Save the pending changes to a `View` record as a Draft
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record
+/// * `data` - The pending changes to the `View` record
+///
+/// ## Returns
+/// * `Result<ViewDraft, ServerFnError>` - The saved Draft
+#[server(name = SaveDraftHandler, prefix = "/api/v1", endpoint = "views/draft")]
+async fn save_draft(id: String, data: ViewUpdate) -> Result<ViewDraft, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseApiRequests};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::EditViews).await?;
+
+    // Declare mutable, sanitise, and run Request & Custom validation
+    let mut data: ViewUpdate = data;
+    data.sanitise();
+    data.is_valid()?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Save the Draft
+    let data: ViewDraft = ViewDraft::save(&mut transaction, &id, &data).await?;
+
+    // Commit the transaction and return the Draft
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Publish a `View` record, promoting its Draft
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to publish
+///
+/// ## Returns
+/// * `Result<PerseView, ServerFnError>` - The published View
+#[server(name = PublishViewHandler, prefix = "/api/v1", endpoint = "views/publish")]
+async fn publish_view(id: String) -> Result<PerseView, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::PublishViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Publish the View
+    let data: PerseView = PerseView::publish(&mut transaction, &id).await?;
+
+    // Commit the transaction and return the published View
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Create a signed, expiring link to preview the Draft of a `View` record
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to preview
+///
+/// ## Returns
+/// * `Result<String, ServerFnError>` - The Preview link
+#[server(name = PreviewLinkHandler, prefix = "/api/v1", endpoint = "views/preview")]
+async fn create_preview_link(id: String) -> Result<String, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseDatabaseModels};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ViewDrafts).await?;
+
+    // Preview the View through its own path, or the Homepage
+    let view: PerseView = PerseView::get_by_id(Database::get()?, &id).await?;
+    let token: String = PerseView::preview_token(&id)?;
+
+    Ok(match view.is_homepage {
+        true => format!("/?preview={token}"),
+        false => format!("/{}?preview={token}", view.path),
+    })
+}
+
+/// # Move a `View` record to the trash
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to delete
+///
+/// ## Returns
+/// * `Result<PerseView, ServerFnError>` - The deleted View
+#[server(name = DeleteViewHandler, prefix = "/api/v1", endpoint = "views/delete")]
+async fn delete_view(id: String) -> Result<PerseView, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseDatabaseModels};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::EditViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Delete the View
+    let data: PerseView = PerseView::delete(&mut transaction, &id).await?;
+
+    // Ensure the User can change the View, before the changes are committed
+    user.authorise_view_change(&data)?;
+
+    // Commit the transaction and return the deleted View
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Schedule a `View` record to be published, unpublished or become the Homepage
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to schedule
+/// * `data` - The dates to schedule
+///
+/// ## Returns
+/// * `Result<PerseView, ServerFnError>` - The scheduled View
+#[server(name = ScheduleViewHandler, prefix = "/api/v1", endpoint = "views/schedule")]
+async fn schedule_view(id: String, data: ViewSchedule) -> Result<PerseView, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::PublishViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Schedule the View
+    let data: PerseView = PerseView::schedule(&mut transaction, &id, &data).await?;
+
+    // Commit the transaction and return the scheduled View
+    transaction.commit().await?;
+
+    Ok(data)
+}
@@ -1,5 +1,6 @@
 use leptos::*;
 use leptos_meta::*;
+use perse_utils::results::PerseError;
 
 /// ## View for Not Found (404)
 #[component]
@@ -28,7 +29,39 @@ pub fn NotFound(err: Option<ServerFnError>) -> impl IntoView {
                 <main>
                     <p>"The page you are looking for does not exist."</p>
 
-                    {err.map(|err| view! { <p><strong>"Error:"</strong> {err.to_string()}</p> })}
+                    {err.map(|err| view! { <p><strong>"Error:"</strong> {PerseError::from(err).to_string()}</p> })}
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// ## View for Internal Server Error (500)
+#[component]
+pub fn InternalError() -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    {
+        use actix_web::http::StatusCode;
+        use leptos_actix::ResponseOptions;
+
+        // Configure Response
+        let resp: ResponseOptions = expect_context();
+        resp.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    view! {
+        // Set Metadata
+        <Title text="Server Error | Perse" />
+        <Meta name="description" content="Something went wrong while loading this page." />
+
+        <main>
+            <article>
+                <header>
+                    <h1>"Server Error"</h1>
+                </header>
+
+                <main>
+                    <p>"Something went wrong while loading this page. Please try again later."</p>
                 </main>
             </article>
         </main>
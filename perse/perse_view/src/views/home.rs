@@ -1,16 +1,23 @@
 use leptos::*;
 use leptos_meta::*;
+use leptos_router::*;
 
 // # Modules
-use super::errors::NotFound;
-use perse_data::views::schema::View as PerseView;
+use super::errors::{InternalError, NotFound};
+use perse_data::views::schema::RenderedView;
+use perse_utils::results::{ErrorTypes, PerseError};
 
 // # Components
-use crate::components::{initial_state::InitialState, loader::Loader, PerseComponent};
+use crate::components::{
+    content_body::ContentBody, head_content::HeadContent, initial_state::InitialState, loader::Loader, PerseComponent,
+};
 
 /// # View for the "Home"
 #[component]
 pub fn Home() -> impl IntoView {
+    let query = use_query_map();
+    let preview = move || query.with(|q| q.get("preview").cloned());
+
     // ## Server Functions
 
     // Create a Server API for the Get Homepage request
@@ -28,7 +35,7 @@ pub fn Home() -> impl IntoView {
         // Signal source
         get_home_signal,
         // Loader
-        |_signal_count| async move { get_home().await },
+        move |_signal_count| async move { get_home(preview()).await },
     );
 
     // Action for the Get Homepage signal
@@ -40,17 +47,22 @@ pub fn Home() -> impl IntoView {
     view! {
         <Transition fallback=|| Loader::build(None).into_view()>
             // Action for the Get Homepage signal
-            {move || get_home_signal_action().map(|response| response.map(|view| view! {
+            {move || get_home_signal_action().map(|response| response.map(|RenderedView { view, head, body }| view! {
                     // Set Metadata
                     <Title text=view.title />
                     <Meta name="description" content=view.description.unwrap_or_default() />
+                    {preview().map(|_| view! { <Meta name="robots" content="noindex" /> })}
+                {HeadContent::build_iter(Some(head))}
 
-                    {view.content_body}
+                    {ContentBody::build(body)}
                 }
                 .into_view())
-                // View for the server error
-                .unwrap_or_else(|err| view! { <NotFound err=Some(err) /> }.into_view()
-            ))
+                // View for the server error, or if there is no Homepage
+                .unwrap_or_else(|err| match PerseError::from(err.clone()).error_type() {
+                    ErrorTypes::NotFound => view! { <NotFound err=Some(err) /> }.into_view(),
+                    _ => view! { <InternalError /> }.into_view(),
+                })
+            )
             // Initial state
             .unwrap_or_else(|| Some(InitialState::build(None)).collect_view())
             }
@@ -60,12 +72,24 @@ pub fn Home() -> impl IntoView {
 
 /// # Retrieve the Homepage `View` from the database
 ///
+/// ## Fields
+/// * `preview` - A signed Preview token, to render a View's unpublished changes instead
+///
 /// ## Returns  
-/// * `Result<View, ServerFnError>` - A list of views
+/// * `Result<RenderedView, ServerFnError>` - The Homepage, prepared for rendering
 #[server(name = GetAllHandler, prefix = "/api/v1", endpoint = "views/home")]
-async fn get_home() -> Result<PerseView, ServerFnError> {
-    use perse_data::Database;
+async fn get_home(preview: Option<String>) -> Result<RenderedView, ServerFnError> {
+    use perse_data::{views::schema::View as PerseView, Database};
+
+    // Get the previewed View
+    if let Some(token) = preview {
+        return Ok(PerseView::get_preview(Database::get()?, &token, None).await?.render());
+    }
 
     // Get the HomePage View
-    Ok(PerseView::get_homepage(Database::get()?).await?)
+    let view: PerseView = PerseView::get_homepage(Database::get()?)
+        .await?
+        .ok_or_else(|| PerseError::new(ErrorTypes::NotFound, "No View has been made the Homepage yet."))?;
+
+    Ok(view.render())
 }
@@ -0,0 +1,170 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::users::schema::UserLogin;
+use perse_utils::results::PerseError;
+
+// # Components
+use crate::components::{navbar::NavBar, stylesheet::PerseStylesheet, PerseComponent};
+use crate::APP_NAME;
+
+/// # View for "Sign in"
+#[component]
+pub fn Login() -> impl IntoView {
+    // ## Server Functions
+
+    // Create a Frontend API for the Login request
+    let login_api = Action::<LoginHandler, _>::server();
+
+    // ## Signals
+
+    // Signal for the login response
+    let login_signal = Signal::derive(move || login_api.value().get());
+
+    // Page to return to once signed in
+    let query = use_query_map();
+    let next = move || query.with(|query| query.get("next").cloned()).unwrap_or_default();
+
+    // ## Views
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Sign in | Perse" />
+        <Meta name="description" content="Sign in to manage a Perse site" />
+        <Meta name="robots" content="noindex" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"Sign in"</h1></header>
+
+                <main>
+                    <ActionForm action=login_api>
+                        <input name="next" type="hidden" value=next />
+
+                        <div>
+                            <label for="username">"Username"<sup> "*"</sup></label>
+                            <input id="username" name="data[username]" type="text" autocomplete="username" required />
+                        </div>
+                        <div>
+                            <label for="password">"Password"<sup> "*"</sup></label>
+                            <input id="password" name="data[password]" type="password" autocomplete="current-password" required />
+                        </div>
+
+                        <div>
+                            <button type="submit" aria-label="Sign in">"Sign in"</button>
+                            <br />
+                        </div>
+                    </ActionForm>
+
+                    <aside>
+                        // Action for the Login signal
+                        {move || login_signal.get().and_then(Result::err).map(|err| view! {
+                            <header><h4>"Something went wrong"</h4></header>
+                            <p>{PerseError::from(err).to_string()}</p>
+                        })}
+                    </aside>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # View for "Sign out"
+#[component]
+pub fn Logout() -> impl IntoView {
+    // ## Server Functions
+
+    // Create a Frontend API for the Logout request
+    let logout_api = Action::<LogoutHandler, _>::server();
+
+    // ## Views
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Sign out | Perse" />
+        <Meta name="description" content="Sign out of a Perse site" />
+        <Meta name="robots" content="noindex" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"Sign out"</h1></header>
+
+                <main>
+                    <ActionForm action=logout_api>
+                        <div>
+                            <button type="submit" aria-label="Sign out">"Sign out"</button>
+                            <br />
+                        </div>
+                    </ActionForm>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Sign in a User, and return to the requested page
+///
+/// ## Fields
+/// * `data` - The username and password of the User
+/// * `next` - The page to return to once signed in, if it is part of the admin area
+///
+/// ## Returns
+/// * `Result<(), ServerFnError>` - Returns void
+#[server(name = LoginHandler, prefix = "/api/v1", endpoint = "login")]
+async fn login(data: UserLogin, next: Option<String>) -> Result<(), ServerFnError> {
+    use actix_session::Session;
+    use perse_data::{
+        users::schema::{User, SESSION_USER_ID},
+        Database, PerseApiRequests,
+    };
+
+    // Declare mutable, sanitise, and run Request & Custom validation
+    let mut data: UserLogin = data;
+    data.sanitise();
+    data.is_valid()?;
+
+    // Authenticate the User
+    let user: User = User::authenticate(Database::get()?, &data).await?;
+
+    // Start a new session for the User
+    let session: Session = leptos_actix::extract().await?;
+    session.renew();
+    session.insert(SESSION_USER_ID, user.id)?;
+
+    // Only return to pages within the admin area
+    let next: String = next
+        .filter(|next| next.starts_with("/p/") && !next.starts_with("/p/login"))
+        .unwrap_or("/p/new".to_string());
+    leptos_actix::redirect(&next);
+
+    Ok(())
+}
+
+/// # Sign out the current User
+///
+/// ## Returns
+/// * `Result<(), ServerFnError>` - Returns void
+#[server(name = LogoutHandler, prefix = "/api/v1", endpoint = "logout")]
+async fn logout() -> Result<(), ServerFnError> {
+    use actix_session::Session;
+
+    // End the session
+    let session: Session = leptos_actix::extract().await?;
+    session.purge();
+
+    leptos_actix::redirect("/p/login");
+
+    Ok(())
+}
@@ -1,3 +1,11 @@
+pub mod api_tokens;
+pub mod edit;
 pub mod errors;
 pub mod home;
+pub mod login;
 pub mod new;
+pub mod redirects;
+pub mod revisions;
+pub mod settings;
+pub mod trash;
+pub mod users;
@@ -2,10 +2,11 @@ use leptos::*;
 use leptos_meta::*;
 use leptos_router::*;
 use perse_data::views::schema::{NewView, View as PerseView};
+use perse_utils::results::{FieldErrors, PerseError};
 
 // # Components
 use crate::components::{
-    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    field_errors::FieldErrorList, initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
     view_list::ViewList, PerseComponent,
 };
 use crate::APP_NAME;
@@ -39,6 +40,50 @@ pub fn New() -> impl IntoView {
         |new_view_response| async move { new_view_response },
     );
 
+    // ### Field Errors signal
+
+    // Signal for the validation errors found in the browser, before the form is submitted
+    let (client_errors_signal, set_client_errors_signal) = create_signal(FieldErrors::default());
+
+    // Validate the form in the browser, and only send it to the server if every field is valid
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+
+        match NewViewHandler::from_event(&ev) {
+            Ok(request) => {
+                let errors: FieldErrors = request.data.field_errors();
+
+                if errors.is_empty() {
+                    new_view_api.dispatch(request);
+                }
+
+                set_client_errors_signal.set(errors);
+            }
+            Err(err) => {
+                set_client_errors_signal.set(FieldErrors::default());
+                new_view_api.value().set(Some(Err(ServerFnError::Serialization(err.to_string()))));
+            }
+        }
+    };
+
+    // Action for the validation errors of a field, from the browser or else the server
+    let field_errors_action = move |field: &'static str| {
+        move || {
+            let client_errors: FieldErrors = client_errors_signal.get();
+            let errors: FieldErrors = if client_errors.is_empty() {
+                new_view_signal
+                    .get()
+                    .and_then(Result::err)
+                    .map(|err| PerseError::from(err).fields().clone())
+                    .unwrap_or_default()
+            } else {
+                client_errors
+            };
+
+            FieldErrorList::build_iter(errors.get(field))
+        }
+    };
+
     // Action for the New View signal
     let new_view_signal_action = move || {
         new_view_signal_resource.get().and_then(|response| {
@@ -85,7 +130,7 @@ pub fn New() -> impl IntoView {
                 <header><h1>"New View"</h1></header>
 
                 <main>
-                    <ActionForm action=new_view_api>
+                    <form action=new_view_api.url() method="post" on:submit=on_submit>
                         <div>
                             <div>
                                 <label for="visibility">"Visibility"</label>
@@ -98,27 +143,65 @@ pub fn New() -> impl IntoView {
                             <div>
                                 <label for="title">"Title"<sup> "*"</sup></label>
                                 <input id="title" name="data[title]" type="text" placeholder="About Me" required />
+                                {field_errors_action("title")}
                             </div>
                             <div>
                                 <label for="content_body">"Body Content"</label>
                                 <textarea id="content_body" name="data[content_body]" placeholder="It was a bright cold day in April..."></textarea>
+                                {field_errors_action("content_body")}
+                            </div>
+                            <div>
+                                <label for="content_format">"Body Format"</label>
+                                <select id="content_format" name="data[content_format]">
+                                    <option value="FormatPlain">"Plain text"</option>
+                                    <option value="FormatMarkdown">"Markdown"</option>
+                                    <option value="FormatHtml">"HTML"</option>
+                                </select>
                             </div>
                             <div>
                                 <label for="content_head">"Head Content"</label>
                                 <textarea id="content_head" name="data[content_head]" placeholder=""></textarea>
+                                {field_errors_action("content_head")}
                             </div>
                             <div>
                                 <label for="description">"Description"</label>
                                 <textarea id="description" name="data[description]" placeholder=""></textarea>
+                                {field_errors_action("description")}
+                            </div>
+                            <div>
+                                <label for="parent_id">"Parent View"</label>
+                                <Transition fallback=loader>
+                                    <select id="parent_id" name="data[parent_id]">
+                                        <option value="">"None (top level)"</option>
+                                        // Action for the Get Views signal
+                                        {move || views_list_signal_action().and_then(Result::ok).map(|views| views
+                                            .into_iter()
+                                            .filter_map(|view| view.id.map(|id| view! {
+                                                <option value=id.to_string()>{format!("/{} ({})", view.path, view.title)}</option>
+                                            }))
+                                            .collect_view())}
+                                    </select>
+                                </Transition>
                             </div>
                             <div>
                                 <label for="route">"Route"</label>
-                                <input id="route" name="data[route]" type="text" placeholder="about-me" required />
+                                <input id="route" name="data[route]" type="text" placeholder="Generated from the title, such as about-me" />
+                                {field_errors_action("route")}
+                            </div>
+                            <div>
+                                <label for="tags">"Tags"</label>
+                                <input id="tags" name="data[tags]" type="text" placeholder="news, releases" />
+                                {field_errors_action("tags")}
                             </div>
                             <br />
                             <div>
                                 <label for="is_homepage">"Is this the new homepage?"</label>
                                 <input id="is_homepage" name="data[is_homepage]" type="checkbox" />
+                                {field_errors_action("is_homepage")}
+                            </div>
+                            <div>
+                                <label for="is_draft">"Save as a draft, without publishing?"</label>
+                                <input id="is_draft" name="data[is_draft]" type="checkbox" />
                             </div>
                             <br />
                         </div>
@@ -143,13 +226,21 @@ pub fn New() -> impl IntoView {
                                             </main>
                                         </main>
                                     }.into_view())
-                                    // View for the New View server error
-                                    .unwrap_or_else(|err| view! {
-                                        <header><h4>"Something went wrong"</h4></header>
-                                        <main>
-                                            <p>{err.to_string()}</p>
-                                        </main>
-                                    }.into_view())
+                                    // View for the New View server error, where invalid fields are shown next to their inputs
+                                    .unwrap_or_else(|err| {
+                                        let err: PerseError = PerseError::from(err);
+                                        let message: String = match err.fields().is_empty() {
+                                            true => err.to_string(),
+                                            false => "Some of the fields are invalid, please correct them and try again.".to_string(),
+                                        };
+
+                                        view! {
+                                            <header><h4>"Something went wrong"</h4></header>
+                                            <main>
+                                                <p>{message}</p>
+                                            </main>
+                                        }.into_view()
+                                    })
                                 })
                                 // Initial state
                                 .unwrap_or_else(|| {
@@ -157,11 +248,17 @@ pub fn New() -> impl IntoView {
                                 })}
                             </Transition>
                         </aside>
-                    </ActionForm>
+                    </form>
 
                     <Transition fallback=loader>
                         <section>
                             <header><h2>"Your Views"</h2></header>
+                            <p><a href="/p/trash" title="Trash" aria-label="Trash">"View the trash"</a></p>
+                            <p><a href="/p/redirects" title="Redirects" aria-label="Redirects">"Manage redirects"</a></p>
+                            <p><a href="/p/settings" title="Settings" aria-label="Settings">"Manage settings"</a></p>
+                            <p><a href="/p/users" title="Users" aria-label="Users">"Manage users"</a></p>
+                            <p><a href="/p/tokens" title="API Tokens" aria-label="API Tokens">"Manage API tokens"</a></p>
+                            <p><a href="/p/logout" title="Sign out" aria-label="Sign out">"Sign out"</a></p>
 
                             <main>
                                 // Action for the Get Views signal
@@ -176,7 +273,7 @@ pub fn New() -> impl IntoView {
                                             <br />
                                             <section>
                                                 <header><h4>"Something went wrong"</h4></header>
-                                                <p>{err.to_string()}</p>
+                                                <p>{PerseError::from(err).to_string()}</p>
                                             </section>
                                         }}).collect_view()
                                     })
@@ -203,15 +300,26 @@ pub fn New() -> impl IntoView {
 /// * `Result<PerseView, ServerFnError>` - The successful response as a Perse View
 #[server(name = NewViewHandler, prefix = "/api/v1", endpoint = "new")]
 async fn new_view(data: NewView) -> Result<PerseView, ServerFnError> {
-    use perse_data::{Database, PerseApiRequests, PerseDatabaseModels};
+    use perse_data::{users::schema::UserPermissions, Database, PerseApiRequests, PerseDatabaseModels};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::EditViews).await?;
 
-    // Declare mutable, and run Request & Custom validation
+    // Declare mutable, sanitise, and run Request & Custom validation
     let mut data: NewView = data;
+    data.sanitise();
     data.is_valid()?;
 
+    // Ensure the User can publish the View, unless it is saved as a draft
+    user.authorise_new_view(&data)?;
+
     // Get a database connection and start a transaction
     let mut transaction = Database::get()?.begin().await?;
 
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
     // Determine the URL path
     data.route = NewView::determine_url_path(&mut transaction, &data).await?;
 
@@ -229,8 +337,12 @@ async fn new_view(data: NewView) -> Result<PerseView, ServerFnError> {
 /// ## Returns  
 /// * `Result<Vec<View>, ServerFnError>` - A list of views
 #[server(name = GetAllHandler, prefix = "/api/v1", endpoint = "views")]
-async fn get_all_views() -> Result<Vec<PerseView>, ServerFnError> {
-    use perse_data::{Database, PerseDatabaseModels};
+pub(crate) async fn get_all_views() -> Result<Vec<PerseView>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseDatabaseModels};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ViewDrafts).await?;
 
     // Get a database connection
     let conn = Database::get()?;
@@ -0,0 +1,292 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::redirects::schema::{NewRedirect, Redirect, RedirectMatchTypes};
+use perse_utils::results::PerseError;
+
+// # Modules
+use super::new::get_all_views;
+
+// # Components
+use crate::components::{
+    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    PerseComponent,
+};
+use crate::APP_NAME;
+
+/// # View for the "Redirects"
+#[component]
+pub fn Redirects() -> impl IntoView {
+    // ## Server Functions
+
+    // Create a Frontend API for the New Redirect request
+    let new_redirect_api = Action::<NewRedirectHandler, _>::server();
+
+    // Create a Frontend API for the Delete Redirect request
+    let delete_redirect_api = Action::<DeleteRedirectHandler, _>::server();
+
+    // Create a Server API for the Get Redirects request
+    create_server_action::<GetRedirectsHandler>();
+
+    // ## Signals
+
+    // Signal for the new and delete redirect responses
+    let new_redirect_signal = Signal::derive(move || new_redirect_api.value().get());
+    let delete_redirect_signal = Signal::derive(move || delete_redirect_api.value().get());
+
+    // ### Get Redirects signal
+
+    // Resource for tracking the Get Redirects signal, refreshed after every create or delete
+    let get_redirects_signal_resource = create_resource(
+        // Signal source
+        move || (new_redirect_api.version().get(), delete_redirect_api.version().get()),
+        // Loader
+        |_signal_count| async move { get_redirects().await },
+    );
+
+    // Action for the Get Redirects signal
+    let get_redirects_signal_action = move || get_redirects_signal_resource.get();
+
+    // ### Get Views signal
+
+    // Resource for tracking the Get Views signal, used to choose a target View
+    let get_all_views_signal_resource = create_resource(
+        // Signal source
+        || (),
+        // Loader
+        |_| async move { get_all_views().await },
+    );
+
+    // Action for the Get Views signal
+    let views_list_signal_action = move || get_all_views_signal_resource.get();
+
+    // ## Views
+
+    // Components
+    let loader = move || Loader::build(None).into_view();
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Redirects | Perse" />
+        <Meta name="description" content="Manage the redirects of a Perse site" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"Redirects"</h1></header>
+
+                <main>
+                    <ActionForm action=new_redirect_api>
+                        <div>
+                            <div>
+                                <label for="source">"Source"<sup> "*"</sup></label>
+                                <input id="source" name="data[source]" type="text" placeholder="old-docs" required />
+                            </div>
+                            <div>
+                                <label for="match_type">"Match"</label>
+                                <select id="match_type" name="data[match_type]">
+                                    <option value="MatchExact">"Exact path"</option>
+                                    <option value="MatchPrefix">"Path prefix"</option>
+                                    <option value="MatchRegex">"Regular expression"</option>
+                                </select>
+                            </div>
+                            <div>
+                                <label for="target_path">"Target Path"</label>
+                                <input id="target_path" name="data[target_path]" type="text" placeholder="docs" />
+                            </div>
+                            <div>
+                                <label for="target_view_id">"Target View"</label>
+                                <Transition fallback=loader>
+                                    <select id="target_view_id" name="data[target_view_id]">
+                                        <option value="">"None (use the target path)"</option>
+                                        // Action for the Get Views signal
+                                        {move || views_list_signal_action().and_then(Result::ok).map(|views| views
+                                            .into_iter()
+                                            .filter_map(|view| view.id.map(|id| view! {
+                                                <option value=id.to_string()>{format!("/{} ({})", view.path, view.title)}</option>
+                                            }))
+                                            .collect_view())}
+                                    </select>
+                                </Transition>
+                            </div>
+                            <div>
+                                <label for="status_code">"Status"</label>
+                                <select id="status_code" name="data[status_code]">
+                                    <option value="301">"301 (Permanent)"</option>
+                                    <option value="302">"302 (Temporary)"</option>
+                                </select>
+                            </div>
+                            <br />
+                        </div>
+
+                        <div>
+                            <button type="submit" aria-label="Save Redirect">"Save"</button>
+                            <br />
+                        </div>
+                    </ActionForm>
+
+                    <aside>
+                        // Action for the New Redirect signal
+                        {move || new_redirect_signal.get().map(|response| {
+                            response.map(|redirect| view! {
+                                <p>{format!("Requests for {} are now redirected.", redirect.source)}</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+
+                        // Action for the Delete Redirect signal
+                        {move || delete_redirect_signal.get().map(|response| {
+                            response.map(|_| view! {
+                                <p>"The redirect has been deleted."</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+                    </aside>
+
+                    <Transition fallback=loader>
+                        <section>
+                            // Action for the Get Redirects signal
+                            {move || get_redirects_signal_action().map(|response| {
+                                // View for the Get Redirects result
+                                response.map(|redirects| {
+                                    if redirects.is_empty() {
+                                        return view! { <p>"There are no redirects."</p> }.into_view();
+                                    }
+
+                                    view! {
+                                        <ul class="view_list">
+                                            {redirects.into_iter().map(|redirect| {
+                                                let source = match redirect.match_type {
+                                                    RedirectMatchTypes::MatchExact => format!("/{}", redirect.source),
+                                                    RedirectMatchTypes::MatchPrefix => format!("/{}/*", redirect.source),
+                                                    RedirectMatchTypes::MatchRegex => redirect.source,
+                                                };
+                                                let target = match (redirect.target_path, redirect.target_view_path) {
+                                                    (Some(target_path), _) => target_path,
+                                                    (None, Some(target_view_path)) => format!("/{target_view_path}"),
+                                                    (None, None) => "(a View in the trash)".to_string(),
+                                                };
+
+                                                view! {
+                                                    <li class="view_list-item">
+                                                        <span>{format!("{source} → {target} ({}), {} hits", redirect.status_code, redirect.hits)}</span>
+
+                                                        <ActionForm action=delete_redirect_api>
+                                                            <input name="id" type="hidden" value=redirect.id.to_string() />
+                                                            <button type="submit" aria-label="Delete Redirect">"Delete"</button>
+                                                        </ActionForm>
+                                                    </li>
+                                                }
+                                            }).collect_view()}
+                                        </ul>
+                                    }.into_view()
+                                })
+                                // View for the server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <p>{PerseError::from(err).to_string()}</p>
+                                }.into_view())
+                            })
+                            // Initial state
+                            .unwrap_or_else(|| {
+                                Some(InitialState::build(None)).collect_view()
+                            })}
+                        </section>
+                    </Transition>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Retrieve the collection of `Redirect` records from the database
+///
+/// ## Returns
+/// * `Result<Vec<Redirect>, ServerFnError>` - A list of redirects, in the order they are matched
+#[server(name = GetRedirectsHandler, prefix = "/api/v1", endpoint = "redirects")]
+async fn get_redirects() -> Result<Vec<Redirect>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ViewDrafts).await?;
+
+    // Get the Redirects
+    Ok(Redirect::get_all(Database::get()?).await?)
+}
+
+/// # Create a new `Redirect` record in the database
+///
+/// ## Fields
+/// * `data` - The data to create a new `Redirect` record with
+///
+/// ## Returns
+/// * `Result<Redirect, ServerFnError>` - The new Redirect
+#[server(name = NewRedirectHandler, prefix = "/api/v1", endpoint = "redirects/new")]
+async fn new_redirect(data: NewRedirect) -> Result<Redirect, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseApiRequests};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::PublishViews).await?;
+
+    // Declare mutable, sanitise, and run Request & Custom validation
+    let mut data: NewRedirect = data;
+    data.sanitise();
+    data.is_valid()?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Create the new Redirect
+    let data: Redirect = Redirect::create(&mut transaction, &data).await?;
+
+    // Commit the transaction and return the new Redirect
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Delete a `Redirect` record
+///
+/// ## Fields
+/// * `id` - The ID of the `Redirect` record to delete
+///
+/// ## Returns
+/// * `Result<(), ServerFnError>` - Returns void
+#[server(name = DeleteRedirectHandler, prefix = "/api/v1", endpoint = "redirects/delete")]
+async fn delete_redirect(id: String) -> Result<(), ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::PublishViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Delete the Redirect
+    Redirect::delete(&mut transaction, &id).await?;
+
+    // Commit the transaction
+    transaction.commit().await?;
+
+    Ok(())
+}
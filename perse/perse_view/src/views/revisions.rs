@@ -0,0 +1,276 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::views::schema::{
+    View as PerseView, ViewRevision, ViewRevisionDiff, ViewRevisionDiffTypes,
+};
+use perse_utils::results::PerseError;
+
+// # Components
+use crate::components::{
+    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    view_list::ViewList, PerseComponent, PerseStyle,
+};
+use crate::APP_NAME;
+
+/// # Revisions Request Parameters
+#[derive(Params, Clone, Debug, PartialEq, Eq)]
+struct RevisionsParams {
+    id: Option<String>,
+}
+
+/// # View for the "Revisions" of a View
+#[component]
+pub fn Revisions() -> impl IntoView {
+    let query = use_params::<RevisionsParams>();
+    let requested_id = move || {
+        query.with(|q| {
+            q.as_ref()
+                .map(|q| q.id.clone().unwrap_or_default())
+                .unwrap_or_default()
+        })
+    };
+
+    // ## Server Functions
+
+    // Create a Frontend API for the Compare Revisions request
+    let diff_revisions_api = Action::<DiffRevisionsHandler, _>::server();
+
+    // Create a Frontend API for the Restore Revision request
+    let restore_revision_api = Action::<RestoreRevisionHandler, _>::server();
+
+    // Create a Server API for the Get Revisions request
+    create_server_action::<GetRevisionsHandler>();
+
+    // ## Signals
+
+    // Signal for the compare and restore revision responses
+    let diff_revisions_signal = Signal::derive(move || diff_revisions_api.value().get());
+    let restore_revision_signal = Signal::derive(move || restore_revision_api.value().get());
+
+    // ### Get Revisions signal
+
+    // Resource for tracking the Get Revisions signal, refreshed after every restore
+    let get_revisions_signal_resource = create_resource(
+        // Signal source
+        move || (requested_id(), restore_revision_api.version().get()),
+        // Loader
+        |(id, _signal_count)| async move { get_revisions(id).await },
+    );
+
+    // Action for the Get Revisions signal
+    let get_revisions_signal_action = move || get_revisions_signal_resource.get();
+
+    // ## Views
+
+    // Components
+    let loader = move || Loader::build(None).into_view();
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Revisions | Perse" />
+        <Meta name="description" content="Compare and restore revisions of a Perse view" />
+        {PerseStylesheet::build()}
+        {style()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header>
+                    <h1>"Revisions"</h1>
+                    <a href=move || format!("/p/edit/{}", requested_id())>"Back to the editor"</a>
+                </header>
+
+                <main>
+                    <Transition fallback=loader>
+                        // Action for the Get Revisions signal
+                        {move || get_revisions_signal_action().map(|response| {
+                            // View for the Get Revisions result
+                            response.map(|revisions| {
+                                let options = {
+                                    let revisions = revisions.clone();
+                                    move || revisions.iter().map(|revision| view! {
+                                        <option value=revision.id.to_string()>
+                                            {format!("{} by {}: {}", revision.created_at, revision.author.clone().unwrap_or("Unknown".to_string()), revision.title)}
+                                        </option>
+                                    }).collect_view()
+                                };
+
+                                view! {
+                                    <ActionForm action=diff_revisions_api>
+                                        <div>
+                                            <label for="from">"Compare"</label>
+                                            <select id="from" name="from">{options()}</select>
+                                            <label for="to">"with"</label>
+                                            <select id="to" name="to">{options()}</select>
+                                            <button type="submit" aria-label="Compare Revisions">"Compare"</button>
+                                        </div>
+                                    </ActionForm>
+
+                                    <ul class="revision_list">
+                                        {revisions.into_iter().map(|revision| view! {
+                                            <li class="revision_list-item">
+                                                <span>{format!("{} by {}: {} (/{})", revision.created_at, revision.author.unwrap_or("Unknown".to_string()), revision.title, revision.route)}</span>
+
+                                                <ActionForm action=restore_revision_api>
+                                                    <input name="id" type="hidden" value=revision.id.to_string() />
+                                                    <button type="submit" aria-label="Restore this Revision">"Restore this revision"</button>
+                                                </ActionForm>
+                                            </li>
+                                        }).collect_view()}
+                                    </ul>
+                                }.into_view()
+                            })
+                            // View for the server error
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })
+                        // Initial state
+                        .unwrap_or_else(|| {
+                            Some(InitialState::build(None)).collect_view()
+                        })}
+                    </Transition>
+
+                    <aside>
+                        // Action for the Restore Revision signal
+                        {move || restore_revision_signal.get().map(|response| {
+                            response.map(|view| view! {
+                                <header><h2>"Restored"</h2></header>
+                                <main>{ViewList::build(Some(view))}</main>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+
+                        // Action for the Compare Revisions signal
+                        {move || diff_revisions_signal.get().map(|response| {
+                            response.map(|lines| view! {
+                                <pre class="revision_diff">
+                                    {lines.into_iter().map(|line| {
+                                        let marker = match line.change {
+                                            ViewRevisionDiffTypes::Added => "+ ",
+                                            ViewRevisionDiffTypes::Removed => "- ",
+                                            ViewRevisionDiffTypes::Unchanged => "  ",
+                                        };
+
+                                        view! {
+                                            <span class=format!("revision_diff-{}", line.change)>{marker}{line.content}"\n"</span>
+                                        }
+                                    }).collect_view()}
+                                </pre>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+                    </aside>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Implement the Revisions page style
+///
+/// ## Returns
+/// * `Fragment` - The style as a `Fragment`
+fn style() -> impl IntoView {
+    let css: &str = "
+    pre.revision_diff span.revision_diff-added {
+        background: #e6ffec;
+    }
+
+    pre.revision_diff span.revision_diff-removed {
+        background: #ffebe9;
+    }
+    ";
+
+    PerseStyle::from_css(css)
+}
+
+/// # Retrieve the Revisions of a `View` record
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record
+///
+/// ## Returns
+/// * `Result<Vec<ViewRevision>, ServerFnError>` - A list of revisions, newest first
+#[server(name = GetRevisionsHandler, prefix = "/api/v1", endpoint = "views/revisions")]
+async fn get_revisions(id: String) -> Result<Vec<ViewRevision>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ViewDrafts).await?;
+
+    // Get the View's Revisions
+    Ok(PerseView::revisions(Database::get()?, &id).await?)
+}
+
+/// # Compare two Revisions of a `View` record
+///
+/// ## Fields
+/// * `from` - The ID of the older Revision
+/// * `to` - The ID of the newer Revision
+///
+/// ## Returns
+/// * `Result<Vec<ViewRevisionDiff>, ServerFnError>` - The line by line differences
+#[server(name = DiffRevisionsHandler, prefix = "/api/v1", endpoint = "views/revisions/diff")]
+async fn diff_revisions(from: String, to: String) -> Result<Vec<ViewRevisionDiff>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ViewDrafts).await?;
+
+    // Get a database connection
+    let conn = Database::get()?;
+
+    // Retrieve and compare both Revisions
+    let from: ViewRevision = ViewRevision::get_by_id(conn, &from).await?;
+    let to: ViewRevision = ViewRevision::get_by_id(conn, &to).await?;
+
+    Ok(from.diff(&to))
+}
+
+/// # Restore a `View` record to one of its Revisions
+///
+/// ## Fields
+/// * `id` - The ID of the Revision to restore
+///
+/// ## Returns
+/// * `Result<PerseView, ServerFnError>` - The restored View
+#[server(name = RestoreRevisionHandler, prefix = "/api/v1", endpoint = "views/revisions/restore")]
+async fn restore_revision(id: String) -> Result<PerseView, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::EditViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Restore the Revision, which records a new Revision
+    let data: PerseView = PerseView::restore_revision(&mut transaction, &id).await?;
+
+    // Ensure the User can change the View, before the changes are committed
+    user.authorise_view_change(&data)?;
+
+    // Commit the transaction and return the restored View
+    transaction.commit().await?;
+
+    Ok(data)
+}
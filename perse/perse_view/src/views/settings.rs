@@ -0,0 +1,166 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::settings::schema::{SettingKeys, SettingUpdate};
+use perse_utils::results::PerseError;
+
+// # Components
+use crate::components::{
+    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    PerseComponent,
+};
+use crate::APP_NAME;
+
+/// # View for the "Settings"
+#[component]
+pub fn Settings() -> impl IntoView {
+    // ## Server Functions
+
+    // Create a Frontend API for the Update Setting request
+    let update_setting_api = Action::<UpdateSettingHandler, _>::server();
+
+    // Create a Server API for the Get Setting request
+    create_server_action::<GetSettingHandler>();
+
+    // ## Signals
+
+    // Signal for the update setting response
+    let update_setting_signal = Signal::derive(move || update_setting_api.value().get());
+
+    // ### Get robots.txt signal
+
+    // Resource for tracking the Get robots.txt signal, refreshed after every update
+    let get_robots_txt_signal_resource = create_resource(
+        // Signal source
+        move || update_setting_api.version().get(),
+        // Loader
+        |_signal_count| async move { get_setting(SettingKeys::RobotsTxt).await },
+    );
+
+    // Action for the Get robots.txt signal
+    let get_robots_txt_signal_action = move || get_robots_txt_signal_resource.get();
+
+    // ## Views
+
+    // Components
+    let loader = move || Loader::build(None).into_view();
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Settings | Perse" />
+        <Meta name="description" content="Manage the settings of a Perse site" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"Settings"</h1></header>
+
+                <main>
+                    <Transition fallback=loader>
+                        // Action for the Get robots.txt signal
+                        {move || get_robots_txt_signal_action().map(|response| {
+                            // View for the Get robots.txt result
+                            response.map(|robots_txt| view! {
+                                <ActionForm action=update_setting_api>
+                                    <input name="data[key]" type="hidden" value=SettingKeys::RobotsTxt.to_string() />
+
+                                    <div>
+                                        <label for="robots_txt">"robots.txt"</label>
+                                        <p>"Leave this empty to keep system pages and unlisted views out of search engines. Sites outside of production always disallow everything."</p>
+                                        <textarea id="robots_txt" name="data[value]" placeholder="User-agent: *\nAllow: /">{robots_txt}</textarea>
+                                    </div>
+
+                                    <div>
+                                        <button type="submit" aria-label="Save robots.txt">"Save"</button>
+                                        <br />
+                                    </div>
+                                </ActionForm>
+                            }.into_view())
+                            // View for the server error
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })
+                        // Initial state
+                        .unwrap_or_else(|| {
+                            Some(InitialState::build(None)).collect_view()
+                        })}
+                    </Transition>
+
+                    <aside>
+                        // Action for the Update Setting signal
+                        {move || update_setting_signal.get().map(|response| {
+                            response.map(|_| view! {
+                                <p>"Your settings have been saved."</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+                    </aside>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Retrieve the overridden value of a setting
+///
+/// ## Fields
+/// * `key` - The setting to retrieve
+///
+/// ## Returns
+/// * `Result<Option<String>, ServerFnError>` - The value of the setting, if it has been overridden
+#[server(name = GetSettingHandler, prefix = "/api/v1", endpoint = "settings")]
+async fn get_setting(key: SettingKeys) -> Result<Option<String>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ManageSettings).await?;
+
+    // Get the Setting
+    Ok(key.get(Database::get()?).await?)
+}
+
+/// # Override the value of a setting, or remove the override
+///
+/// ## Fields
+/// * `data` - The setting and its new value
+///
+/// ## Returns
+/// * `Result<(), ServerFnError>` - Returns void
+#[server(name = UpdateSettingHandler, prefix = "/api/v1", endpoint = "settings/update")]
+async fn update_setting(data: SettingUpdate) -> Result<(), ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseApiRequests};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::ManageSettings).await?;
+
+    // Declare mutable, sanitise, and run Request & Custom validation
+    let mut data: SettingUpdate = data;
+    data.sanitise();
+    data.is_valid()?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Update the Setting
+    data.key.set(&mut transaction, data.value.as_deref()).await?;
+
+    // Commit the transaction
+    transaction.commit().await?;
+
+    Ok(())
+}
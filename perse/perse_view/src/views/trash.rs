@@ -0,0 +1,222 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::views::schema::View as PerseView;
+use perse_utils::results::PerseError;
+
+// # Components
+use crate::components::{
+    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    PerseComponent,
+};
+use crate::APP_NAME;
+
+/// # View for the "Trash"
+#[component]
+pub fn Trash() -> impl IntoView {
+    // ## Server Functions
+
+    // Create a Frontend API for the Restore View request
+    let restore_view_api = Action::<RestoreViewHandler, _>::server();
+
+    // Create a Frontend API for the Purge View request
+    let purge_view_api = Action::<PurgeViewHandler, _>::server();
+
+    // Create a Server API for the Get Trashed Views request
+    create_server_action::<GetTrashedHandler>();
+
+    // ## Signals
+
+    // Signal for the restore and purge view responses
+    let restore_view_signal = Signal::derive(move || restore_view_api.value().get());
+    let purge_view_signal = Signal::derive(move || purge_view_api.value().get());
+
+    // ### Get Trashed Views signal
+
+    // Resource for tracking the Get Trashed Views signal, refreshed after every restore or purge
+    let get_trashed_signal_resource = create_resource(
+        // Signal source
+        move || (restore_view_api.version().get(), purge_view_api.version().get()),
+        // Loader
+        |_signal_count| async move { get_trashed_views().await },
+    );
+
+    // Action for the Get Trashed Views signal
+    let get_trashed_signal_action = move || get_trashed_signal_resource.get();
+
+    // ## Views
+
+    // Components
+    let loader = move || Loader::build(None).into_view();
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Trash | Perse" />
+        <Meta name="description" content="Restore or permanently delete trashed Perse views" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"Trash"</h1></header>
+
+                <main>
+                    <aside>
+                        // Action for the Restore View signal
+                        {move || restore_view_signal.get().map(|response| {
+                            response.map(|view| view! {
+                                <p>"Restored " <a href={format!("/p/edit/{}", view.id.map(|id| id.to_string()).unwrap_or_default())}>{view.title}</a> "."</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+
+                        // Action for the Purge View signal
+                        {move || purge_view_signal.get().map(|response| {
+                            response.map(|_| view! {
+                                <p>"The view has been permanently deleted."</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+                    </aside>
+
+                    <Transition fallback=loader>
+                        <section>
+                            // Action for the Get Trashed Views signal
+                            {move || get_trashed_signal_action().map(|response| {
+                                // View for the Get Trashed Views result
+                                response.map(|views| {
+                                    if views.is_empty() {
+                                        return view! { <p>"The trash is empty."</p> }.into_view();
+                                    }
+
+                                    view! {
+                                        <ul class="view_list">
+                                            {views.into_iter().map(|view| {
+                                                let restore_id = view.id.map(|id| id.to_string()).unwrap_or_default();
+                                                let purge_id = restore_id.clone();
+
+                                                view! {
+                                                    <li class="view_list-item">
+                                                        <span>{format!("/{} ({})", view.path, view.title)}</span>
+
+                                                        <ActionForm action=restore_view_api>
+                                                            <input name="id" type="hidden" value=restore_id />
+                                                            <button type="submit" aria-label="Restore View">"Restore"</button>
+                                                        </ActionForm>
+
+                                                        <ActionForm action=purge_view_api>
+                                                            <input name="id" type="hidden" value=purge_id />
+                                                            <button type="submit" aria-label="Permanently delete View">"Delete permanently"</button>
+                                                        </ActionForm>
+                                                    </li>
+                                                }
+                                            }).collect_view()}
+                                        </ul>
+                                    }.into_view()
+                                })
+                                // View for the server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <p>{PerseError::from(err).to_string()}</p>
+                                }.into_view())
+                            })
+                            // Initial state
+                            .unwrap_or_else(|| {
+                                Some(InitialState::build(None)).collect_view()
+                            })}
+                        </section>
+                    </Transition>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Retrieve the collection of trashed `View` records from the database
+///
+/// ## Returns
+/// * `Result<Vec<View>, ServerFnError>` - A list of trashed views
+#[server(name = GetTrashedHandler, prefix = "/api/v1", endpoint = "views/trash")]
+async fn get_trashed_views() -> Result<Vec<PerseView>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ViewDrafts).await?;
+
+    // Get the trashed Views
+    Ok(PerseView::get_trashed(Database::get()?).await?)
+}
+
+/// # Restore a trashed `View` record
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to restore
+///
+/// ## Returns
+/// * `Result<PerseView, ServerFnError>` - The restored View
+#[server(name = RestoreViewHandler, prefix = "/api/v1", endpoint = "views/restore")]
+async fn restore_view(id: String) -> Result<PerseView, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::EditViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Restore the View
+    let data: PerseView = PerseView::restore(&mut transaction, &id).await?;
+
+    // Ensure the User can change the View, before the changes are committed
+    user.authorise_view_change(&data)?;
+
+    // Commit the transaction and return the restored View
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Permanently delete a trashed `View` record
+///
+/// ## Fields
+/// * `id` - The ID of the `View` record to purge
+///
+/// ## Returns
+/// * `Result<(), ServerFnError>` - Returns void
+#[server(name = PurgeViewHandler, prefix = "/api/v1", endpoint = "views/purge")]
+async fn purge_view(id: String) -> Result<(), ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::PublishViews).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Attribute the changes to the User
+    user.set_author(&mut transaction).await?;
+
+    // Purge the View
+    PerseView::purge(&mut transaction, &id).await?;
+
+    // Commit the transaction
+    transaction.commit().await?;
+
+    Ok(())
+}
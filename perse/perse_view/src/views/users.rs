@@ -0,0 +1,303 @@
+use leptos::*;
+use leptos_meta::*;
+use leptos_router::*;
+use perse_data::users::schema::{NewUser, User, UserRoleTypes};
+use perse_utils::results::PerseError;
+
+// # Components
+use crate::components::{
+    initial_state::InitialState, loader::Loader, navbar::NavBar, stylesheet::PerseStylesheet,
+    PerseComponent,
+};
+use crate::APP_NAME;
+
+/// # View for the "Users"
+#[component]
+pub fn Users() -> impl IntoView {
+    // ## Server Functions
+
+    // Create a Frontend API for the New User request
+    let new_user_api = Action::<NewUserHandler, _>::server();
+
+    // Create a Frontend API for the Update User Role request
+    let update_user_role_api = Action::<UpdateUserRoleHandler, _>::server();
+
+    // Create a Frontend API for the Delete User request
+    let delete_user_api = Action::<DeleteUserHandler, _>::server();
+
+    // Create a Server API for the Get Users request
+    create_server_action::<GetUsersHandler>();
+
+    // ## Signals
+
+    // Signal for the new, update role and delete user responses
+    let new_user_signal = Signal::derive(move || new_user_api.value().get());
+    let update_user_role_signal = Signal::derive(move || update_user_role_api.value().get());
+    let delete_user_signal = Signal::derive(move || delete_user_api.value().get());
+
+    // ### Get Users signal
+
+    // Resource for tracking the Get Users signal, refreshed after every change
+    let get_users_signal_resource = create_resource(
+        // Signal source
+        move || {
+            (
+                new_user_api.version().get(),
+                update_user_role_api.version().get(),
+                delete_user_api.version().get(),
+            )
+        },
+        // Loader
+        |_signal_count| async move { get_users().await },
+    );
+
+    // Action for the Get Users signal
+    let get_users_signal_action = move || get_users_signal_resource.get();
+
+    // ## Views
+
+    // Components
+    let loader = move || Loader::build(None).into_view();
+    let role_options = move |selected: UserRoleTypes| {
+        [
+            (UserRoleTypes::RoleViewer, "Viewer"),
+            (UserRoleTypes::RoleEditor, "Editor"),
+            (UserRoleTypes::RolePublisher, "Publisher"),
+            (UserRoleTypes::RoleAdmin, "Admin"),
+        ]
+        .into_iter()
+        .map(|(role, label)| view! {
+            <option value=role.to_string() selected=role == selected>{label}</option>
+        })
+        .collect_view()
+    };
+
+    // Main View
+    view! {
+        // Set Metadata
+        <Title text="Users | Perse" />
+        <Meta name="description" content="Manage the users of a Perse site" />
+        {PerseStylesheet::build()}
+
+        <header>
+            {NavBar::build(None)}
+        </header>
+
+        <main>
+            <article class=move || { format!("{APP_NAME}-block") }>
+                <header><h1>"Users"</h1></header>
+
+                <main>
+                    <ActionForm action=new_user_api>
+                        <div>
+                            <div>
+                                <label for="username">"Username"<sup> "*"</sup></label>
+                                <input id="username" name="data[username]" type="text" autocomplete="off" required />
+                            </div>
+                            <div>
+                                <label for="password">"Password"<sup> "*"</sup></label>
+                                <input id="password" name="data[password]" type="password" autocomplete="new-password" minlength="8" required />
+                            </div>
+                            <div>
+                                <label for="role">"Role"</label>
+                                <select id="role" name="data[role]">
+                                    {role_options(UserRoleTypes::default())}
+                                </select>
+                            </div>
+                            <br />
+                        </div>
+
+                        <div>
+                            <button type="submit" aria-label="Save User">"Save"</button>
+                            <br />
+                        </div>
+                    </ActionForm>
+
+                    <aside>
+                        // Action for the New User signal
+                        {move || new_user_signal.get().map(|response| {
+                            response.map(|user| view! {
+                                <p>{format!("{} can now sign in.", user.username)}</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+
+                        // Action for the Update User Role signal
+                        {move || update_user_role_signal.get().map(|response| {
+                            response.map(|user| view! {
+                                <p>{format!("The role of {} has been changed.", user.username)}</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+
+                        // Action for the Delete User signal
+                        {move || delete_user_signal.get().map(|response| {
+                            response.map(|_| view! {
+                                <p>"The user has been deleted."</p>
+                            }.into_view())
+                            .unwrap_or_else(|err| view! {
+                                <header><h4>"Something went wrong"</h4></header>
+                                <p>{PerseError::from(err).to_string()}</p>
+                            }.into_view())
+                        })}
+                    </aside>
+
+                    <Transition fallback=loader>
+                        <section>
+                            // Action for the Get Users signal
+                            {move || get_users_signal_action().map(|response| {
+                                // View for the Get Users result
+                                response.map(|users| view! {
+                                    <ul class="view_list">
+                                        {users.into_iter().map(|user| view! {
+                                            <li class="view_list-item">
+                                                <span>{user.username}</span>
+
+                                                <ActionForm action=update_user_role_api>
+                                                    <input name="id" type="hidden" value=user.id.to_string() />
+                                                    <select name="role" aria-label="Role">
+                                                        {role_options(user.role)}
+                                                    </select>
+                                                    <button type="submit" aria-label="Change Role">"Change role"</button>
+                                                </ActionForm>
+
+                                                <ActionForm action=delete_user_api>
+                                                    <input name="id" type="hidden" value=user.id.to_string() />
+                                                    <button type="submit" aria-label="Delete User">"Delete"</button>
+                                                </ActionForm>
+                                            </li>
+                                        }).collect_view()}
+                                    </ul>
+                                }.into_view())
+                                // View for the server error
+                                .unwrap_or_else(|err| view! {
+                                    <header><h4>"Something went wrong"</h4></header>
+                                    <p>{PerseError::from(err).to_string()}</p>
+                                }.into_view())
+                            })
+                            // Initial state
+                            .unwrap_or_else(|| {
+                                Some(InitialState::build(None)).collect_view()
+                            })}
+                        </section>
+                    </Transition>
+                </main>
+            </article>
+        </main>
+    }
+}
+
+/// # Retrieve the collection of `User` records from the database
+///
+/// ## Returns
+/// * `Result<Vec<User>, ServerFnError>` - A list of users, by username
+#[server(name = GetUsersHandler, prefix = "/api/v1", endpoint = "users")]
+async fn get_users() -> Result<Vec<User>, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ManageUsers).await?;
+
+    // Get the Users
+    Ok(User::get_all(Database::get()?).await?)
+}
+
+/// # Create a new `User` record in the database
+///
+/// ## Fields
+/// * `data` - The data to create a new `User` record with
+///
+/// ## Returns
+/// * `Result<User, ServerFnError>` - The new User
+#[server(name = NewUserHandler, prefix = "/api/v1", endpoint = "users/new")]
+async fn new_user(data: NewUser) -> Result<User, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database, PerseApiRequests};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ManageUsers).await?;
+
+    // Declare mutable, sanitise, and run Request & Custom validation
+    let mut data: NewUser = data;
+    data.sanitise();
+    data.is_valid()?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Create the new User
+    let data: User = User::create(&mut transaction, &data).await?;
+
+    // Commit the transaction and return the new User
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Change the role of a `User` record
+///
+/// ## Fields
+/// * `id` - The ID of the `User` record to change
+/// * `role` - The new role of the User
+///
+/// ## Returns
+/// * `Result<User, ServerFnError>` - The updated User
+#[server(name = UpdateUserRoleHandler, prefix = "/api/v1", endpoint = "users/role")]
+async fn update_user_role(id: String, role: UserRoleTypes) -> Result<User, ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    require_permission(UserPermissions::ManageUsers).await?;
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Change the role of the User
+    let data: User = User::update_role(&mut transaction, &id, role).await?;
+
+    // Commit the transaction and return the updated User
+    transaction.commit().await?;
+
+    Ok(data)
+}
+
+/// # Delete a `User` record
+///
+/// ## Fields
+/// * `id` - The ID of the `User` record to delete
+///
+/// ## Returns
+/// * `Result<(), ServerFnError>` - Returns void
+#[server(name = DeleteUserHandler, prefix = "/api/v1", endpoint = "users/delete")]
+async fn delete_user(id: String) -> Result<(), ServerFnError> {
+    use perse_data::{users::schema::UserPermissions, Database};
+    use perse_utils::results::{ErrorTypes, PerseError};
+    use crate::auth::require_permission;
+
+    // Ensure the signed in User is permitted to do this
+    let user = require_permission(UserPermissions::ManageUsers).await?;
+
+    // Keep the signed in User from locking themselves out
+    if user.id.to_string() == id {
+        Err(PerseError::new(ErrorTypes::Conflict, "You can't delete your own User."))?;
+    }
+
+    // Get a database connection and start a transaction
+    let mut transaction = Database::get()?.begin().await?;
+
+    // Delete the User
+    User::delete(&mut transaction, &id).await?;
+
+    // Commit the transaction
+    transaction.commit().await?;
+
+    Ok(())
+}